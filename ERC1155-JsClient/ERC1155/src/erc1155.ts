@@ -69,14 +69,22 @@ class ERC1155Client {
 
   public async install(
     keys: Keys.AsymmetricKey,
+    name: string,
+    symbol: string,
     uri: string,
+    contractUri: string,
     contractName: string,
     paymentAmount: string,
-    wasmPath: string
+    wasmPath: string,
+    eventsMode: number = 0
   ) {
     const runtimeArgs = RuntimeArgs.fromMap({
+      name: CLValueBuilder.string(name),
+      symbol: CLValueBuilder.string(symbol),
       uri: CLValueBuilder.string(uri),
+      contract_uri: CLValueBuilder.string(contractUri),
       contract_name: CLValueBuilder.string(contractName),
+      events_mode: CLValueBuilder.u8(eventsMode),
     });
 
     const deployHash = await installWasmFile({
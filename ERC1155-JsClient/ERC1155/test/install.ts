@@ -17,7 +17,10 @@ const {
   ERC1155_MASTER_KEY_PAIR_PATH,
   ERC1155_INSTALL_PAYMENT_AMOUNT,
   ERC1155_CONTRACT_NAME,
+  NAME,
+  SYMBOL,
   URI,
+  CONTRACT_URI,
   OPERATOR,
   ERC1155_PACKAGE_HASH,
   ERC1155_PROXY_WASM_PATH,
@@ -50,7 +53,10 @@ const test = async () => {
 
   const installDeployHash = await erc1155.install(
     KEYS,
+    NAME!,
+    SYMBOL!,
     URI!,
+    CONTRACT_URI!,
     ERC1155_CONTRACT_NAME!,
     ERC1155_INSTALL_PAYMENT_AMOUNT!,
     ERC1155_WASM_PATH!
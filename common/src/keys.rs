@@ -2,4 +2,19 @@
 pub const BALANCE_OF: &str = "balance_of";
 pub const BALANCE_OF_BATCH: &str = "balance_of_batch";
 pub const IS_APPROVED_FOR_ALL: &str = "is_approved_for_all";
+pub const ALLOWANCE: &str = "allowance";
+pub const NAME: &str = "name";
+pub const SYMBOL: &str = "symbol";
 pub const URI: &str = "uri";
+pub const CONTRACT_URI: &str = "contract_uri";
+pub const TOTAL_SUPPLY: &str = "total_supply";
+pub const TOTAL_SUPPLY_ALL: &str = "total_supply_all";
+pub const EXISTS: &str = "exists";
+pub const OWNER: &str = "owner";
+pub const HAS_ROLE: &str = "has_role";
+pub const PAUSED: &str = "paused";
+pub const TOKENS_OF_OWNER: &str = "tokens_of_owner";
+pub const HOLDERS_OF: &str = "holders_of";
+pub const OWNER_OF: &str = "owner_of";
+pub const ROYALTY_INFO: &str = "royalty_info";
+pub const SUPPORTS_INTERFACE: &str = "supports_interface";
@@ -0,0 +1,81 @@
+use crate::{
+    data::{RoleAdmins, Roles},
+    env::runtime,
+    erc1155::{ERC1155Event, Error},
+    ERC1155,
+};
+use alloc::string::{String, ToString};
+use casper_types::{ApiError, Key};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+pub const DEFAULT_ADMIN_ROLE: &str = "default_admin_role";
+pub const MINTER_ROLE: &str = "minter_role";
+pub const BURNER_ROLE: &str = "burner_role";
+pub const PAUSER_ROLE: &str = "pauser_role";
+pub const URI_SETTER_ROLE: &str = "uri_setter_role";
+
+pub trait AccessControl<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage>
+{
+    fn init(&mut self) {
+        Roles::init();
+        RoleAdmins::init();
+    }
+    fn has_role(&self, role: String, account: Key) -> bool {
+        Roles::instance().get(&role, &account)
+    }
+    // Roles without an explicit admin are administered by `DEFAULT_ADMIN_ROLE`.
+    fn get_role_admin(&self, role: String) -> String {
+        RoleAdmins::instance()
+            .get(&role)
+            .unwrap_or_else(|| DEFAULT_ADMIN_ROLE.to_string())
+    }
+    fn only_role(&self, role: String) {
+        if !self.has_role(role, self.get_caller()) {
+            runtime::revert(ApiError::from(Error::MissingRole));
+        }
+    }
+    fn grant_role(&mut self, role: String, account: Key) {
+        self.only_role(self.get_role_admin(role.clone()));
+        self._grant_role(role, account);
+    }
+    fn revoke_role(&mut self, role: String, account: Key) {
+        self.only_role(self.get_role_admin(role.clone()));
+        self._revoke_role(role, account);
+    }
+    fn renounce_role(&mut self, role: String, account: Key) {
+        if account != self.get_caller() {
+            runtime::revert(ApiError::from(Error::RenounceRoleForOther));
+        }
+        self._revoke_role(role, account);
+    }
+    fn _set_role_admin(&mut self, role: String, admin_role: String) {
+        let previous_admin_role: String = self.get_role_admin(role.clone());
+        RoleAdmins::instance().set(&role, admin_role.clone());
+        self.erc1155_emit(&ERC1155Event::RoleAdminChanged {
+            role,
+            previous_admin_role,
+            new_admin_role: admin_role,
+        });
+    }
+    fn _grant_role(&mut self, role: String, account: Key) {
+        if !self.has_role(role.clone(), account) {
+            Roles::instance().set(&role, &account, true);
+            self.erc1155_emit(&ERC1155Event::RoleGranted {
+                role,
+                account,
+                sender: self.get_caller(),
+            });
+        }
+    }
+    fn _revoke_role(&mut self, role: String, account: Key) {
+        if self.has_role(role.clone(), account) {
+            Roles::instance().set(&role, &account, false);
+            self.erc1155_emit(&ERC1155Event::RoleRevoked {
+                role,
+                account,
+                sender: self.get_caller(),
+            });
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! Casper Event Standard (CES) emission.
+//!
+//! Schemas are written to `__events_schema` once at init and every event is appended to the
+//! `__events` dictionary under its index, with `__events_length` holding the next index.
+//! An event is stored as `Bytes`: its name prefixed with `event_`, followed by its fields in
+//! schema order, all in bytesrepr encoding.
+use crate::{
+    env::{get_key, set_key, Dict, UnwrapOrRevert},
+    erc1155::{ERC1155Event, Error},
+};
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    CLType, CLTyped,
+};
+use core::convert::TryFrom;
+
+pub const EVENTS: &str = "__events";
+pub const EVENTS_LENGTH: &str = "__events_length";
+pub const EVENTS_SCHEMA: &str = "__events_schema";
+pub const CES_VERSION_KEY: &str = "__events_ces_version";
+pub const CES_VERSION: &str = "0.2.0";
+pub const EVENT_PREFIX: &str = "event_";
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventsMode {
+    /// Every event is written to its own URef, see `ERC1155::erc1155_emit`.
+    Native = 0,
+    /// Every event goes to the CES events dictionary instead.
+    CES = 1,
+}
+
+impl TryFrom<u8> for EventsMode {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(EventsMode::Native),
+            1 => Ok(EventsMode::CES),
+            _ => Err(Error::InvalidEventsMode),
+        }
+    }
+}
+
+/// Field names and types of every event, keyed by event name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schemas(pub BTreeMap<String, Vec<(String, CLType)>>);
+
+impl Schemas {
+    fn with(mut self, name: &str, fields: &[(&str, CLType)]) -> Self {
+        self.0.insert(
+            name.to_string(),
+            fields
+                .iter()
+                .map(|(field, cl_type)| (field.to_string(), cl_type.clone()))
+                .collect(),
+        );
+        self
+    }
+}
+
+impl CLTyped for Schemas {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Schemas {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for Schemas {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (schemas, remainder) = BTreeMap::from_bytes(bytes)?;
+        Ok((Schemas(schemas), remainder))
+    }
+}
+
+pub fn schemas() -> Schemas {
+    let list = CLType::List(Box::new(CLType::U256));
+    Schemas::default()
+        .with(
+            "TransferSingle",
+            &[
+                ("operator", CLType::Key),
+                ("from", CLType::Key),
+                ("to", CLType::Key),
+                ("id", CLType::U256),
+                ("amount", CLType::U256),
+            ],
+        )
+        .with(
+            "TransferBatch",
+            &[
+                ("operator", CLType::Key),
+                ("from", CLType::Key),
+                ("to", CLType::Key),
+                ("ids", list.clone()),
+                ("amounts", list),
+            ],
+        )
+        .with(
+            "ApprovalForAll",
+            &[
+                ("owner", CLType::Key),
+                ("operator", CLType::Key),
+                ("approved", CLType::Bool),
+                ("expires_at", CLType::U64),
+            ],
+        )
+        .with("URI", &[("value", CLType::String), ("id", CLType::U256)])
+        .with("BaseURI", &[("value", CLType::String)])
+        .with(
+            "Approval",
+            &[
+                ("owner", CLType::Key),
+                ("operator", CLType::Key),
+                ("id", CLType::U256),
+                ("amount", CLType::U256),
+            ],
+        )
+        .with(
+            "OwnershipTransferred",
+            &[("previous_owner", CLType::Key), ("new_owner", CLType::Key)],
+        )
+        .with(
+            "RoleGranted",
+            &[
+                ("role", CLType::String),
+                ("account", CLType::Key),
+                ("sender", CLType::Key),
+            ],
+        )
+        .with(
+            "RoleRevoked",
+            &[
+                ("role", CLType::String),
+                ("account", CLType::Key),
+                ("sender", CLType::Key),
+            ],
+        )
+        .with(
+            "RoleAdminChanged",
+            &[
+                ("role", CLType::String),
+                ("previous_admin_role", CLType::String),
+                ("new_admin_role", CLType::String),
+            ],
+        )
+        .with("Paused", &[("account", CLType::Key)])
+        .with("Unpaused", &[("account", CLType::Key)])
+        .with("OperatorFilterModeUpdated", &[("mode", CLType::U8)])
+        .with(
+            "OperatorFilterUpdated",
+            &[("operator", CLType::Key), ("listed", CLType::Bool)],
+        )
+        .with("TransfersLocked", &[("id", CLType::U256)])
+}
+
+pub fn init() {
+    Dict::init(EVENTS);
+    set_key(EVENTS_LENGTH, 0u32);
+    set_key(EVENTS_SCHEMA, schemas());
+    set_key(CES_VERSION_KEY, CES_VERSION.to_string());
+}
+
+/// Encodes `event` as a CES record following its registered schema.
+pub fn encode(event: &ERC1155Event) -> Bytes {
+    let (name, fields) = match event {
+        ERC1155Event::TransferSingle {
+            operator,
+            from,
+            to,
+            id,
+            amount,
+        } => (
+            "TransferSingle",
+            vec![operator as &dyn Encode, from, to, id, amount],
+        ),
+        ERC1155Event::TransferBatch {
+            operator,
+            from,
+            to,
+            ids,
+            amounts,
+        } => (
+            "TransferBatch",
+            vec![operator as &dyn Encode, from, to, ids, amounts],
+        ),
+        ERC1155Event::ApprovalForAll {
+            owner,
+            operator,
+            approved,
+            expires_at,
+        } => (
+            "ApprovalForAll",
+            vec![owner as &dyn Encode, operator, approved, expires_at],
+        ),
+        ERC1155Event::URI { value, id } => ("URI", vec![value as &dyn Encode, id]),
+        ERC1155Event::BaseURI { value } => ("BaseURI", vec![value as &dyn Encode]),
+        ERC1155Event::Approval {
+            owner,
+            operator,
+            id,
+            amount,
+        } => ("Approval", vec![owner as &dyn Encode, operator, id, amount]),
+        ERC1155Event::OwnershipTransferred {
+            previous_owner,
+            new_owner,
+        } => (
+            "OwnershipTransferred",
+            vec![previous_owner as &dyn Encode, new_owner],
+        ),
+        ERC1155Event::RoleGranted {
+            role,
+            account,
+            sender,
+        } => ("RoleGranted", vec![role as &dyn Encode, account, sender]),
+        ERC1155Event::RoleRevoked {
+            role,
+            account,
+            sender,
+        } => ("RoleRevoked", vec![role as &dyn Encode, account, sender]),
+        ERC1155Event::RoleAdminChanged {
+            role,
+            previous_admin_role,
+            new_admin_role,
+        } => (
+            "RoleAdminChanged",
+            vec![role as &dyn Encode, previous_admin_role, new_admin_role],
+        ),
+        ERC1155Event::Paused { account } => ("Paused", vec![account as &dyn Encode]),
+        ERC1155Event::Unpaused { account } => ("Unpaused", vec![account as &dyn Encode]),
+        ERC1155Event::OperatorFilterModeUpdated { mode } => {
+            ("OperatorFilterModeUpdated", vec![mode as &dyn Encode])
+        }
+        ERC1155Event::OperatorFilterUpdated { operator, listed } => (
+            "OperatorFilterUpdated",
+            vec![operator as &dyn Encode, listed],
+        ),
+        ERC1155Event::TransfersLocked { id } => ("TransfersLocked", vec![id as &dyn Encode]),
+    };
+    let mut bytes = format!("{}{}", EVENT_PREFIX, name)
+        .to_bytes()
+        .unwrap_or_revert();
+    for field in fields {
+        field.encode(&mut bytes);
+    }
+    Bytes::from(bytes)
+}
+
+/// Appends `event` to the events dictionary.
+pub fn emit(event: &ERC1155Event) {
+    let length: u32 = get_key(EVENTS_LENGTH).unwrap_or_revert();
+    Dict::instance(EVENTS).set(&length.to_string(), encode(event));
+    set_key(EVENTS_LENGTH, length + 1);
+}
+
+trait Encode {
+    fn encode(&self, bytes: &mut Vec<u8>);
+}
+
+impl<T: ToBytes> Encode for T {
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.to_bytes().unwrap_or_revert());
+    }
+}
@@ -1,16 +1,37 @@
-use alloc::string::String;
-use casper_contract::unwrap_or_revert::UnwrapOrRevert;
+use crate::env::{get_key, set_key, Dict, UnwrapOrRevert};
+use alloc::{format, string::String, vec::Vec};
 use casper_types::{ContractPackageHash, Key, U256};
 
-use casperlabs_contract_utils::{get_key, set_key, Dict};
-
 pub const SELF_CONTRACT_HASH: &str = "self_contract_hash";
 pub const SELF_PACKAGE_HASH: &str = "self_package_hash";
 pub const RESULT: &str = "result";
 pub const OWNERS: &str = "owners";
+pub const OWNER: &str = "owner";
+pub const PAUSED: &str = "paused";
+pub const OPERATOR_FILTER_MODE: &str = "operator_filter_mode";
+pub const EVENTS_MODE: &str = "events_mode";
 pub const NAME: &str = "name";
 pub const SYMBOL: &str = "symbol";
+pub const CONTRACT_URI: &str = "contract_uri";
 pub const BALACNES: &str = "balances";
+pub const SUPPORTED_INTERFACES: &str = "supported_interfaces";
+
+// Entry points a contract recipient must expose and the values it returns to accept tokens.
+pub const ON_ERC1155_RECEIVED: &str = "on_erc1155_received";
+pub const ON_ERC1155_BATCH_RECEIVED: &str = "on_erc1155_batch_received";
+pub const ERC1155_RECEIVED: u32 = 0xf23a6e61;
+pub const ERC1155_BATCH_RECEIVED: u32 = 0xbc197c81;
+
+// Bumped whenever the layout of emitted events changes. Version 1 writes keys and package
+// hashes as formatted strings and separates list entries with `EVENT_LIST_SEPARATOR`.
+pub const EVENT_VERSION: &str = "1";
+pub const EVENT_LIST_SEPARATOR: &str = ",";
+
+// Dictionary item keys are limited to 64 bytes, which the decimal form of a large id exceeds.
+pub fn token_key(token_id: &U256) -> String {
+    format!("{:x}", token_id)
+}
+
 pub struct Balances {
     dict: Dict,
 }
@@ -35,20 +56,25 @@ impl Balances {
     }
 }
 pub const OPERATOR_APPROVALS: &str = "Operator_Approvals";
+pub const OPERATOR_APPROVAL_EXPIRIES: &str = "operator_approval_expiries";
 
+// Operator flags and the block time at which each approval lapses, zero meaning never.
 pub struct OperatorApprovals {
     dict: Dict,
+    expiries: Dict,
 }
 
 impl OperatorApprovals {
     pub fn instance() -> OperatorApprovals {
         OperatorApprovals {
             dict: Dict::instance(OPERATOR_APPROVALS),
+            expiries: Dict::instance(OPERATOR_APPROVAL_EXPIRIES),
         }
     }
 
     pub fn init() {
-        Dict::init(OPERATOR_APPROVALS)
+        Dict::init(OPERATOR_APPROVALS);
+        Dict::init(OPERATOR_APPROVAL_EXPIRIES);
     }
 
     pub fn get(&self, account: &Key, operator: &Key) -> bool {
@@ -60,12 +86,498 @@ impl OperatorApprovals {
     pub fn set(&self, account: &Key, operator: &Key, value: bool) {
         self.dict.set_by_keys((account, operator), value);
     }
+
+    pub fn expires_at(&self, account: &Key, operator: &Key) -> u64 {
+        self.expiries
+            .get_by_keys((account, operator))
+            .unwrap_or_default()
+    }
+
+    pub fn set_expires_at(&self, account: &Key, operator: &Key, value: u64) {
+        self.expiries.set_by_keys((account, operator), value);
+    }
+}
+pub const ALLOWANCES: &str = "allowances";
+
+// Amount of a single id an operator may still move on behalf of its owner.
+pub struct Allowances {
+    dict: Dict,
+}
+
+impl Allowances {
+    pub fn instance() -> Allowances {
+        Allowances {
+            dict: Dict::instance(ALLOWANCES),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(ALLOWANCES)
+    }
+
+    pub fn get(&self, owner: &Key, operator: &Key, token_id: &U256) -> U256 {
+        self.dict
+            .get_by_values((&(*owner, *operator), token_id))
+            .unwrap_or_default()
+    }
+
+    pub fn set(&self, owner: &Key, operator: &Key, token_id: &U256, value: U256) {
+        self.dict
+            .set_by_values((&(*owner, *operator), token_id), value);
+    }
+}
+pub const TOTAL_SUPPLY: &str = "total_supply";
+pub const TOTAL_SUPPLY_ALL: &str = "total_supply_all";
+
+pub struct TotalSupply {
+    dict: Dict,
+}
+
+impl TotalSupply {
+    pub fn instance() -> TotalSupply {
+        TotalSupply {
+            dict: Dict::instance(TOTAL_SUPPLY),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOTAL_SUPPLY)
+    }
+
+    pub fn get(&self, token_id: &U256) -> U256 {
+        self.dict.get(&token_key(token_id)).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &U256, value: U256) {
+        self.dict.set(&token_key(token_id), value);
+    }
+}
+pub const MAX_SUPPLIES: &str = "max_supplies";
+pub const MAX_SUPPLY_LOCKED: &str = "max_supply_locked";
+// Per-id supply caps, zero meaning uncapped, and whether a cap can still be changed.
+pub struct MaxSupplies {
+    caps: Dict,
+    locked: Dict,
+}
+impl MaxSupplies {
+    pub fn instance() -> MaxSupplies {
+        MaxSupplies {
+            caps: Dict::instance(MAX_SUPPLIES),
+            locked: Dict::instance(MAX_SUPPLY_LOCKED),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(MAX_SUPPLIES);
+        Dict::init(MAX_SUPPLY_LOCKED);
+    }
+
+    pub fn get(&self, token_id: &U256) -> U256 {
+        self.caps.get(&token_key(token_id)).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &U256, value: U256) {
+        self.caps.set(&token_key(token_id), value);
+    }
+
+    pub fn locked(&self, token_id: &U256) -> bool {
+        self.locked.get(&token_key(token_id)).unwrap_or_default()
+    }
+
+    pub fn lock(&self, token_id: &U256) {
+        self.locked.set(&token_key(token_id), true);
+    }
+}
+
+pub const TOKEN_KINDS: &str = "token_kinds";
+pub const TOKEN_TYPE_COUNT: &str = "token_type_count";
+pub const NON_FUNGIBLE_MINTED: &str = "non_fungible_minted";
+// Kind of every registered id; ids without an entry are unregistered. Non-fungible ids also
+// record whether they were ever minted, which outlives a burn.
+pub struct TokenTypes {
+    kinds: Dict,
+    minted: Dict,
+}
+impl TokenTypes {
+    pub fn instance() -> TokenTypes {
+        TokenTypes {
+            kinds: Dict::instance(TOKEN_KINDS),
+            minted: Dict::instance(NON_FUNGIBLE_MINTED),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_KINDS);
+        Dict::init(NON_FUNGIBLE_MINTED);
+    }
+
+    pub fn kind(&self, token_id: &U256) -> Option<u8> {
+        self.kinds.get(&token_key(token_id))
+    }
+
+    pub fn set_kind(&self, token_id: &U256, kind: u8) {
+        self.kinds.set(&token_key(token_id), kind);
+    }
+
+    pub fn minted(&self, token_id: &U256) -> bool {
+        self.minted.get(&token_key(token_id)).unwrap_or_default()
+    }
+
+    pub fn set_minted(&self, token_id: &U256) {
+        self.minted.set(&token_key(token_id), true);
+    }
+}
+
+pub const NON_TRANSFERABLE: &str = "non_transferable";
+// Ids whose transfers are locked. Any id can be locked, registered or not.
+pub struct NonTransferable {
+    dict: Dict,
+}
+impl NonTransferable {
+    pub fn instance() -> NonTransferable {
+        NonTransferable {
+            dict: Dict::instance(NON_TRANSFERABLE),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(NON_TRANSFERABLE);
+    }
+
+    pub fn locked(&self, token_id: &U256) -> bool {
+        self.dict.get(&token_key(token_id)).unwrap_or_default()
+    }
+
+    pub fn lock(&self, token_id: &U256) {
+        self.dict.set(&token_key(token_id), true);
+    }
+}
+
+pub const NF_MAX_INDEX: &str = "nf_max_index";
+pub const NF_OWNERS: &str = "nf_owners";
+pub const NF_MINTED: &str = "nf_minted";
+// Last index minted under each non-fungible base type, the owner of each minted item and
+// whether an item was ever minted, which outlives its burn.
+pub struct NonFungibleItems {
+    max_index: Dict,
+    owners: Dict,
+    minted: Dict,
+}
+impl NonFungibleItems {
+    pub fn instance() -> NonFungibleItems {
+        NonFungibleItems {
+            max_index: Dict::instance(NF_MAX_INDEX),
+            owners: Dict::instance(NF_OWNERS),
+            minted: Dict::instance(NF_MINTED),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(NF_MAX_INDEX);
+        Dict::init(NF_OWNERS);
+        Dict::init(NF_MINTED);
+    }
+
+    pub fn max_index(&self, base_type: &U256) -> U256 {
+        self.max_index
+            .get(&token_key(base_type))
+            .unwrap_or_default()
+    }
+
+    pub fn set_max_index(&self, base_type: &U256, index: U256) {
+        self.max_index.set(&token_key(base_type), index);
+    }
+
+    pub fn owner(&self, token_id: &U256) -> Key {
+        self.owners
+            .get(&token_key(token_id))
+            .unwrap_or_else(ZERO_ADDRESS)
+    }
+
+    pub fn set_owner(&self, token_id: &U256, owner: Key) {
+        self.owners.set(&token_key(token_id), owner);
+    }
+
+    pub fn minted(&self, token_id: &U256) -> bool {
+        self.minted.get(&token_key(token_id)).unwrap_or_default()
+    }
+
+    pub fn set_minted(&self, token_id: &U256) {
+        self.minted.set(&token_key(token_id), true);
+    }
+}
+
+pub const NONCES: &str = "nonces";
+// Next nonce every owner has to sign with.
+pub struct Nonces {
+    dict: Dict,
+}
+impl Nonces {
+    pub fn instance() -> Nonces {
+        Nonces {
+            dict: Dict::instance(NONCES),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(NONCES)
+    }
+
+    pub fn get(&self, owner: &Key) -> U256 {
+        self.dict.get_by_key(owner).unwrap_or_default()
+    }
+
+    pub fn set(&self, owner: &Key, nonce: U256) {
+        self.dict.set_by_key(owner, nonce);
+    }
+}
+
+pub const FILTERED_OPERATORS: &str = "filtered_operators";
+// Operators on the allow-list or deny-list of `ERC1155OperatorFilter`.
+pub struct FilteredOperators {
+    dict: Dict,
+}
+impl FilteredOperators {
+    pub fn instance() -> FilteredOperators {
+        FilteredOperators {
+            dict: Dict::instance(FILTERED_OPERATORS),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(FILTERED_OPERATORS)
+    }
+
+    pub fn get(&self, operator: &Key) -> bool {
+        self.dict.get_by_key(operator).unwrap_or_default()
+    }
+
+    pub fn set(&self, operator: &Key, listed: bool) {
+        self.dict.set_by_key(operator, listed);
+    }
+}
+
+pub const ROYALTY_RECEIVERS: &str = "royalty_receivers";
+pub const ROYALTY_FRACTIONS: &str = "royalty_fractions";
+pub const DEFAULT_ROYALTY_RECEIVER: &str = "default_royalty_receiver";
+pub const DEFAULT_ROYALTY_FRACTION: &str = "default_royalty_fraction";
+// Per-id royalty overrides in basis points; ids without a receiver use the default royalty.
+pub struct Royalties {
+    receivers: Dict,
+    fractions: Dict,
+}
+impl Royalties {
+    pub fn instance() -> Royalties {
+        Royalties {
+            receivers: Dict::instance(ROYALTY_RECEIVERS),
+            fractions: Dict::instance(ROYALTY_FRACTIONS),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(ROYALTY_RECEIVERS);
+        Dict::init(ROYALTY_FRACTIONS);
+    }
+
+    pub fn get(&self, token_id: &U256) -> (Key, U256) {
+        (
+            self.receivers
+                .get(&token_key(token_id))
+                .unwrap_or_else(ZERO_ADDRESS),
+            self.fractions.get(&token_key(token_id)).unwrap_or_default(),
+        )
+    }
+
+    pub fn set(&self, token_id: &U256, receiver: Key, fraction: U256) {
+        self.receivers.set(&token_key(token_id), receiver);
+        self.fractions.set(&token_key(token_id), fraction);
+    }
+}
+
+pub const TOKEN_URIS: &str = "token_uris";
+
+pub struct TokenUris {
+    dict: Dict,
+}
+
+impl TokenUris {
+    pub fn instance() -> TokenUris {
+        TokenUris {
+            dict: Dict::instance(TOKEN_URIS),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(TOKEN_URIS)
+    }
+
+    pub fn get(&self, token_id: &U256) -> String {
+        self.dict.get(&token_key(token_id)).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &U256, value: String) {
+        self.dict.set(&token_key(token_id), value);
+    }
+}
+pub const ROLES: &str = "roles";
+
+pub struct Roles {
+    dict: Dict,
+}
+
+impl Roles {
+    pub fn instance() -> Roles {
+        Roles {
+            dict: Dict::instance(ROLES),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(ROLES)
+    }
+
+    pub fn get(&self, role: &str, account: &Key) -> bool {
+        self.dict
+            .get_by_keys((&String::from(role), account))
+            .unwrap_or_default()
+    }
+
+    pub fn set(&self, role: &str, account: &Key, value: bool) {
+        self.dict.set_by_keys((&String::from(role), account), value);
+    }
+}
+pub const ROLE_ADMINS: &str = "role_admins";
+
+pub struct RoleAdmins {
+    dict: Dict,
+}
+
+impl RoleAdmins {
+    pub fn instance() -> RoleAdmins {
+        RoleAdmins {
+            dict: Dict::instance(ROLE_ADMINS),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(ROLE_ADMINS)
+    }
+
+    // Keyed by the hashed role like `Roles`, role names can exceed the dictionary key limit.
+    pub fn get(&self, role: &str) -> Option<String> {
+        self.dict.get_by_values((&String::from(role), &()))
+    }
+
+    pub fn set(&self, role: &str, admin_role: String) {
+        self.dict
+            .set_by_values((&String::from(role), &()), admin_role);
+    }
 }
 #[allow(non_snake_case)]
 pub fn ZERO_ADDRESS() -> Key {
     Key::from_formatted_str("hash-0000000000000000000000000000000000000000000000000000000000000000")
         .unwrap()
 }
+pub const OWNED_TOKENS: &str = "owned_tokens";
+pub const OWNED_TOKENS_INDEX: &str = "owned_tokens_index";
+pub const OWNED_TOKEN_COUNT: &str = "owned_token_count";
+// Ids held by each owner, kept as a dense list with a 1-based position per id (0 = absent).
+pub struct OwnedTokens {
+    tokens: Dict,
+    index: Dict,
+    count: Dict,
+}
+impl OwnedTokens {
+    pub fn instance() -> OwnedTokens {
+        OwnedTokens {
+            tokens: Dict::instance(OWNED_TOKENS),
+            index: Dict::instance(OWNED_TOKENS_INDEX),
+            count: Dict::instance(OWNED_TOKEN_COUNT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(OWNED_TOKENS);
+        Dict::init(OWNED_TOKENS_INDEX);
+        Dict::init(OWNED_TOKEN_COUNT);
+    }
+
+    pub fn get(&self, owner: &Key, index: U256) -> U256 {
+        self.tokens.get_by_keys((&index, owner)).unwrap_or_default()
+    }
+
+    pub fn set(&self, owner: &Key, index: U256, id: U256) {
+        self.tokens.set_by_keys((&index, owner), id);
+    }
+
+    pub fn position(&self, owner: &Key, id: U256) -> U256 {
+        self.index.get_by_keys((&id, owner)).unwrap_or_default()
+    }
+
+    pub fn set_position(&self, owner: &Key, id: U256, position: U256) {
+        self.index.set_by_keys((&id, owner), position);
+    }
+
+    pub fn count(&self, owner: &Key) -> U256 {
+        self.count.get_by_key(owner).unwrap_or_default()
+    }
+
+    pub fn set_count(&self, owner: &Key, count: U256) {
+        self.count.set_by_key(owner, count);
+    }
+}
+
+pub const HOLDERS: &str = "holders";
+pub const HOLDERS_INDEX: &str = "holders_index";
+pub const HOLDER_COUNT: &str = "holder_count";
+// Holders of each id, laid out like `OwnedTokens`.
+pub struct Holders {
+    holders: Dict,
+    index: Dict,
+    count: Dict,
+}
+impl Holders {
+    pub fn instance() -> Holders {
+        Holders {
+            holders: Dict::instance(HOLDERS),
+            index: Dict::instance(HOLDERS_INDEX),
+            count: Dict::instance(HOLDER_COUNT),
+        }
+    }
+
+    pub fn init() {
+        Dict::init(HOLDERS);
+        Dict::init(HOLDERS_INDEX);
+        Dict::init(HOLDER_COUNT);
+    }
+
+    pub fn get(&self, id: U256, index: U256) -> Key {
+        self.holders
+            .get_by_values((&id, &index))
+            .unwrap_or_else(ZERO_ADDRESS)
+    }
+
+    pub fn set(&self, id: U256, index: U256, holder: Key) {
+        self.holders.set_by_values((&id, &index), holder);
+    }
+
+    pub fn position(&self, id: U256, holder: &Key) -> U256 {
+        self.index.get_by_keys((&id, holder)).unwrap_or_default()
+    }
+
+    pub fn set_position(&self, id: U256, holder: &Key, position: U256) {
+        self.index.set_by_keys((&id, holder), position);
+    }
+
+    pub fn count(&self, id: U256) -> U256 {
+        self.count.get(&token_key(&id)).unwrap_or_default()
+    }
+
+    pub fn set_count(&self, id: U256, count: U256) {
+        self.count.set(&token_key(&id), count);
+    }
+}
+
 pub fn uri() -> String {
     get_key("URI").unwrap_or_revert()
 }
@@ -74,6 +586,98 @@ pub fn set_uri(uri: String) {
     set_key("URI", uri);
 }
 
+pub fn name() -> String {
+    get_key(NAME).unwrap_or_default()
+}
+
+pub fn set_name(name: String) {
+    set_key(NAME, name);
+}
+
+pub fn symbol() -> String {
+    get_key(SYMBOL).unwrap_or_default()
+}
+
+pub fn set_symbol(symbol: String) {
+    set_key(SYMBOL, symbol);
+}
+
+pub fn contract_uri() -> String {
+    get_key(CONTRACT_URI).unwrap_or_default()
+}
+
+pub fn set_contract_uri(contract_uri: String) {
+    set_key(CONTRACT_URI, contract_uri);
+}
+
+pub fn total_supply_all() -> U256 {
+    get_key(TOTAL_SUPPLY_ALL).unwrap_or_default()
+}
+
+pub fn set_total_supply_all(total_supply_all: U256) {
+    set_key(TOTAL_SUPPLY_ALL, total_supply_all);
+}
+
+pub fn token_type_count() -> U256 {
+    get_key(TOKEN_TYPE_COUNT).unwrap_or_default()
+}
+
+pub fn set_token_type_count(count: U256) {
+    set_key(TOKEN_TYPE_COUNT, count);
+}
+
+pub fn default_royalty() -> (Key, U256) {
+    (
+        get_key(DEFAULT_ROYALTY_RECEIVER).unwrap_or_else(ZERO_ADDRESS),
+        get_key(DEFAULT_ROYALTY_FRACTION).unwrap_or_default(),
+    )
+}
+
+pub fn set_default_royalty(receiver: Key, fraction: U256) {
+    set_key(DEFAULT_ROYALTY_RECEIVER, receiver);
+    set_key(DEFAULT_ROYALTY_FRACTION, fraction);
+}
+
+pub fn supported_interfaces() -> Vec<u32> {
+    get_key(SUPPORTED_INTERFACES).unwrap_or_default()
+}
+
+pub fn set_supported_interfaces(interfaces: Vec<u32>) {
+    set_key(SUPPORTED_INTERFACES, interfaces);
+}
+
+pub fn owner() -> Key {
+    get_key(OWNER).unwrap_or_else(ZERO_ADDRESS)
+}
+
+pub fn set_owner(owner: Key) {
+    set_key(OWNER, owner);
+}
+
+pub fn events_mode() -> u8 {
+    get_key(EVENTS_MODE).unwrap_or_default()
+}
+
+pub fn set_events_mode(events_mode: u8) {
+    set_key(EVENTS_MODE, events_mode);
+}
+
+pub fn paused() -> bool {
+    get_key(PAUSED).unwrap_or_default()
+}
+
+pub fn set_paused(paused: bool) {
+    set_key(PAUSED, paused);
+}
+
+pub fn operator_filter_mode() -> u8 {
+    get_key(OPERATOR_FILTER_MODE).unwrap_or_default()
+}
+
+pub fn set_operator_filter_mode(mode: u8) {
+    set_key(OPERATOR_FILTER_MODE, mode);
+}
+
 pub fn set_hash(contract_hash: Key) {
     set_key(SELF_CONTRACT_HASH, contract_hash);
 }
@@ -0,0 +1,12 @@
+// Host functions used by the ERC1155 traits. Contracts get the casper runtime, while the
+// `test-runtime` feature swaps in the in-memory implementation from `crate::testing`.
+#[cfg(not(feature = "test-runtime"))]
+pub use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+#[cfg(not(feature = "test-runtime"))]
+pub use casperlabs_contract_utils::{get_key, set_key, Dict};
+
+#[cfg(feature = "test-runtime")]
+pub use crate::testing::{get_key, runtime, set_key, storage, Dict, UnwrapOrRevert};
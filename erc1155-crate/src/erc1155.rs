@@ -1,18 +1,18 @@
-use crate::data::{self, Balances, OperatorApprovals, ZERO_ADDRESS};
+use crate::{
+    ces::{self, EventsMode},
+    data::{self, Allowances, Balances, OperatorApprovals, ZERO_ADDRESS},
+    env::{runtime, storage, UnwrapOrRevert},
+    erc165,
+};
 use alloc::{
     collections::BTreeMap,
     string::{String, ToString},
     vec::Vec,
 };
-use casper_contract::{
-    contract_api::{runtime, storage},
-    unwrap_or_revert::UnwrapOrRevert,
-};
 use casper_types::{
     bytesrepr::Bytes, runtime_args, ApiError, ContractPackageHash, Key, RuntimeArgs, URef, U256,
 };
-use casperlabs_contract_utils::{set_key, ContractContext, ContractStorage};
-use core::ptr::eq;
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
 
 #[repr(u16)]
 pub enum Error {
@@ -28,6 +28,36 @@ pub enum Error {
     MismatchIdsAndAccLength,
     Erc115RejectedToken1,
     Erc115RejectedToken2,
+    InvalidReceiver,
+    SupplyOverflow,
+    CallerIsNotOwner,
+    NewOwnerIsZeroAddress,
+    MissingRole,
+    RenounceRoleForOther,
+    Paused,
+    NotPaused,
+    InvalidEventsMode,
+    InvalidPageSize,
+    MaxSupplyExceeded,
+    MaxSupplyLocked,
+    MaxSupplyBelowTotalSupply,
+    InvalidTokenKind,
+    UnregisteredTokenType,
+    NonFungibleAmount,
+    NonTransferable,
+    NonFungibleAlreadyMinted,
+    NotNonFungibleType,
+    InvalidNonFungibleIndex,
+    InvalidRoyaltyFraction,
+    InvalidRoyaltyReceiver,
+    PermitExpired,
+    InvalidNonce,
+    InvalidSignature,
+    InvalidSigner,
+    InsufficientAllowance,
+    InvalidApprovalExpiry,
+    InvalidOperatorFilterMode,
+    OperatorNotAllowed,
 }
 
 impl From<Error> for ApiError {
@@ -35,6 +65,7 @@ impl From<Error> for ApiError {
         ApiError::User(error as u16)
     }
 }
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ERC1155Event {
     TransferBatch {
         operator: Key,
@@ -54,6 +85,55 @@ pub enum ERC1155Event {
         owner: Key,
         operator: Key,
         approved: bool,
+        expires_at: u64,
+    },
+    URI {
+        value: String,
+        id: U256,
+    },
+    BaseURI {
+        value: String,
+    },
+    Approval {
+        owner: Key,
+        operator: Key,
+        id: U256,
+        amount: U256,
+    },
+    OwnershipTransferred {
+        previous_owner: Key,
+        new_owner: Key,
+    },
+    RoleGranted {
+        role: String,
+        account: Key,
+        sender: Key,
+    },
+    RoleRevoked {
+        role: String,
+        account: Key,
+        sender: Key,
+    },
+    RoleAdminChanged {
+        role: String,
+        previous_admin_role: String,
+        new_admin_role: String,
+    },
+    Paused {
+        account: Key,
+    },
+    Unpaused {
+        account: Key,
+    },
+    OperatorFilterModeUpdated {
+        mode: u8,
+    },
+    OperatorFilterUpdated {
+        operator: Key,
+        listed: bool,
+    },
+    TransfersLocked {
+        id: U256,
     },
 }
 
@@ -78,22 +158,88 @@ impl ERC1155Event {
                 owner: _,
                 operator: _,
                 approved: _,
+                expires_at: _,
             } => "approval for all",
+            ERC1155Event::URI { value: _, id: _ } => "URI",
+            ERC1155Event::BaseURI { value: _ } => "BaseURI",
+            ERC1155Event::Approval {
+                owner: _,
+                operator: _,
+                id: _,
+                amount: _,
+            } => "Approval",
+            ERC1155Event::OwnershipTransferred {
+                previous_owner: _,
+                new_owner: _,
+            } => "OwnershipTransferred",
+            ERC1155Event::RoleGranted {
+                role: _,
+                account: _,
+                sender: _,
+            } => "RoleGranted",
+            ERC1155Event::RoleRevoked {
+                role: _,
+                account: _,
+                sender: _,
+            } => "RoleRevoked",
+            ERC1155Event::RoleAdminChanged {
+                role: _,
+                previous_admin_role: _,
+                new_admin_role: _,
+            } => "RoleAdminChanged",
+            ERC1155Event::Paused { account: _ } => "Paused",
+            ERC1155Event::Unpaused { account: _ } => "Unpaused",
+            ERC1155Event::OperatorFilterModeUpdated { mode: _ } => "OperatorFilterModeUpdated",
+            ERC1155Event::OperatorFilterUpdated {
+                operator: _,
+                listed: _,
+            } => "OperatorFilterUpdated",
+            ERC1155Event::TransfersLocked { id: _ } => "TransfersLocked",
         }
         .to_string()
     }
 }
 pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
-    fn init(&self, uri: String, contract_hash: Key, package_hash: ContractPackageHash) {
+    fn init(
+        &self,
+        name: String,
+        symbol: String,
+        uri: String,
+        contract_hash: Key,
+        package_hash: ContractPackageHash,
+        events_mode: EventsMode,
+    ) {
+        data::set_name(name);
+        data::set_symbol(symbol);
         data::set_uri(uri);
         data::set_hash(contract_hash);
         data::set_package_hash(package_hash);
+        data::set_events_mode(events_mode as u8);
         Balances::init();
         OperatorApprovals::init();
+        Allowances::init();
+        erc165::register_interface(erc165::ERC1155_INTERFACE_ID);
+        erc165::register_interface(erc165::ERC1155_METADATA_URI_INTERFACE_ID);
+        if events_mode == EventsMode::CES {
+            ces::init();
+        }
+    }
+    fn name(&self) -> String {
+        data::name()
+    }
+    fn symbol(&self) -> String {
+        data::symbol()
     }
     fn uri(&self) -> String {
         data::uri()
     }
+    // Collection-level metadata, as opposed to the per-id metadata behind `uri`.
+    fn contract_uri(&self) -> String {
+        data::contract_uri()
+    }
+    fn _set_contract_uri(&mut self, contract_uri: String) {
+        data::set_contract_uri(contract_uri);
+    }
     fn balance_of(&self, account: Key, id: U256) -> U256 {
         if !(account != data::ZERO_ADDRESS()) {
             runtime::revert(ApiError::from(Error::InvalidOwner));
@@ -115,15 +261,35 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
     fn set_approval_for_all(&mut self, operator: Key, approved: bool) {
         self._set_approval_for_all(self.get_caller(), operator, approved);
     }
+    // Approvals given with `set_approval_for_all_until` lapse once the block time reaches
+    // their expiry.
+    fn set_approval_for_all_until(&mut self, operator: Key, expires_at: u64) {
+        if u64::from(runtime::get_blocktime()) >= expires_at {
+            runtime::revert(ApiError::from(Error::InvalidApprovalExpiry));
+        }
+        self._set_approval_for_all_until(self.get_caller(), operator, true, expires_at);
+    }
     fn is_approved_for_all(&mut self, account: Key, operator: Key) -> bool {
-        OperatorApprovals::instance().get(&account, &operator)
+        let approvals = OperatorApprovals::instance();
+        if !approvals.get(&account, &operator) {
+            return false;
+        }
+        let expires_at: u64 = approvals.expires_at(&account, &operator);
+        expires_at == 0 || u64::from(runtime::get_blocktime()) < expires_at
+    }
+    fn approve(&mut self, operator: Key, id: U256, amount: U256) {
+        self._approve(self.get_caller(), operator, id, amount);
+    }
+    fn allowance(&self, owner: Key, operator: Key, id: U256) -> U256 {
+        Allowances::instance().get(&owner, &operator, &id)
     }
     fn safe_transfer_from(&mut self, from: Key, to: Key, id: U256, amount: U256, data: String) {
         let _data: Bytes = Bytes::from(data.as_bytes());
-        if !(from == self.get_caller() || self.is_approved_for_all(from, self.get_caller())) {
-            runtime::revert(ApiError::from(Error::NotOwnerNotApproved));
+        let operator: Key = self.get_caller();
+        if !(from == operator || self.is_approved_for_all(from, operator)) {
+            self._spend_allowance(from, operator, id, amount);
         }
-        self._safe_transfer_from(from, to, id, amount, _data);
+        self._safe_transfer_from(operator, from, to, id, amount, _data);
     }
     fn safe_batch_transfer_from(
         &mut self,
@@ -134,16 +300,29 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
         data: String,
     ) {
         let _data: Bytes = Bytes::from(data.as_bytes());
-        if !(from == self.get_caller() || self.is_approved_for_all(from, self.get_caller())) {
-            runtime::revert(ApiError::from(Error::NotOwnerNotApproved));
+        let operator: Key = self.get_caller();
+        if !(from == operator || self.is_approved_for_all(from, operator)) {
+            if !(ids.len() == amounts.len()) {
+                runtime::revert(ApiError::from(Error::MismatchIdsAndLength));
+            }
+            for (id, amount) in ids.iter().zip(amounts.iter()) {
+                self._spend_allowance(from, operator, *id, *amount);
+            }
         }
-        self._safe_batch_transfer_from(from, to, ids, amounts, _data);
+        self._safe_batch_transfer_from(operator, from, to, ids, amounts, _data);
     }
-    fn _safe_transfer_from(&mut self, from: Key, to: Key, id: U256, amount: U256, _data: Bytes) {
+    fn _safe_transfer_from(
+        &mut self,
+        operator: Key,
+        from: Key,
+        to: Key,
+        id: U256,
+        amount: U256,
+        _data: Bytes,
+    ) {
         if !(to != data::ZERO_ADDRESS()) {
             runtime::revert(ApiError::from(Error::TransferToZeroAddress));
         }
-        let operator: Key = self.get_caller();
 
         let ids: Vec<U256> = self._as_singleton_array(id);
         let amounts: Vec<U256> = self._as_singleton_array(amount);
@@ -179,6 +358,7 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
 
     fn _safe_batch_transfer_from(
         &mut self,
+        operator: Key,
         from: Key,
         to: Key,
         ids: Vec<U256>,
@@ -188,10 +368,9 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
         if !(ids.len() == amounts.len()) {
             runtime::revert(ApiError::from(Error::MismatchIdsAndLength));
         }
-        if !(to != self.get_caller()) {
+        if !(to != data::ZERO_ADDRESS()) {
             runtime::revert(ApiError::from(Error::TransferToZeroAddress));
         }
-        let operator: Key = self.get_caller();
         self._before_token_transfer(
             operator,
             from,
@@ -278,7 +457,7 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
         )
     }
     fn _mint_batch(&mut self, to: Key, ids: Vec<U256>, amounts: Vec<U256>, _data: Bytes) {
-        if !(to != self.get_caller()) {
+        if !(to != data::ZERO_ADDRESS()) {
             runtime::revert(ApiError::from(Error::MintToZeroAddress));
         }
         if !(ids.len() == amounts.len()) {
@@ -406,17 +585,57 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
         );
     }
     fn _set_approval_for_all(&mut self, owner: Key, operator: Key, approved: bool) {
+        self._set_approval_for_all_until(owner, operator, approved, 0);
+    }
+    fn _set_approval_for_all_until(
+        &mut self,
+        owner: Key,
+        operator: Key,
+        approved: bool,
+        expires_at: u64,
+    ) {
         if !(owner != operator) {
             runtime::revert(ApiError::from(Error::SettingApprovalForSelf));
         }
-        OperatorApprovals::instance().set(&owner, &operator, approved);
+        self._before_approval_for_all(owner, operator, approved);
+        let approvals = OperatorApprovals::instance();
+        approvals.set(&owner, &operator, approved);
+        approvals.set_expires_at(&owner, &operator, expires_at);
         self.erc1155_emit(&ERC1155Event::ApprovalForAll {
             owner,
             operator,
             approved,
+            expires_at,
         });
     }
 
+    fn _approve(&mut self, owner: Key, operator: Key, id: U256, amount: U256) {
+        if owner == operator {
+            runtime::revert(ApiError::from(Error::SettingApprovalForSelf));
+        }
+        Allowances::instance().set(&owner, &operator, &id, amount);
+        self.erc1155_emit(&ERC1155Event::Approval {
+            owner,
+            operator,
+            id,
+            amount,
+        });
+    }
+    // Operators without an allowance are reverted with `NotOwnerNotApproved`, as before
+    // allowances existed.
+    fn _spend_allowance(&mut self, owner: Key, operator: Key, id: U256, amount: U256) {
+        let current: U256 = self.allowance(owner, operator, id);
+        if current.is_zero() {
+            runtime::revert(ApiError::from(Error::NotOwnerNotApproved));
+        }
+        if current < amount {
+            runtime::revert(ApiError::from(Error::InsufficientAllowance));
+        }
+        self._approve(owner, operator, id, current - amount);
+    }
+
+    fn _before_approval_for_all(&mut self, _owner: Key, _operator: Key, _approved: bool) {}
+
     fn _before_token_transfer(
         &mut self,
         _operator: Key,
@@ -439,44 +658,64 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
     }
     fn _do_safe_transfer_acceptance_check(
         &mut self,
-        _operator: Key,
-        _from: Key,
-        _to: Key,
-        _id: U256,
-        _amount: U256,
-        _data: Bytes,
+        operator: Key,
+        from: Key,
+        to: Key,
+        id: U256,
+        amount: U256,
+        data: Bytes,
     ) {
-        let v = _to.to_formatted_string();
-        let ch = v.chars().next().unwrap();
-        set_key("ch", ch.to_string());
-        if eq(&ch, &'H') {
-            let _ret: Vec<u8> = runtime::call_contract(
-                _to.into_hash().unwrap_or_revert().into(),
-                "on_erc1155_received",
-                runtime_args! {},
-            );
-            runtime::revert(ApiError::from(Error::Erc115RejectedToken1));
+        match to {
+            Key::Account(_) => {}
+            Key::Hash(package_hash) => {
+                let response: u32 = runtime::call_versioned_contract(
+                    ContractPackageHash::new(package_hash),
+                    None,
+                    data::ON_ERC1155_RECEIVED,
+                    runtime_args! {
+                        "operator" => operator,
+                        "from" => from,
+                        "id" => id,
+                        "amount" => amount,
+                        "data" => data
+                    },
+                );
+                if response != data::ERC1155_RECEIVED {
+                    runtime::revert(ApiError::from(Error::Erc115RejectedToken1));
+                }
+            }
+            _ => runtime::revert(ApiError::from(Error::InvalidReceiver)),
         }
     }
     fn _do_safe_batch_transfer_acceptance_check(
         &mut self,
-        _operator: Key,
-        _from: Key,
-        _to: Key,
-        _ids: Vec<U256>,
-        _amounts: Vec<U256>,
-        _data: Bytes,
+        operator: Key,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        data: Bytes,
     ) {
-        let v = _to.to_formatted_string();
-        let ch = v.chars().next().unwrap();
-        set_key("ch", ch.to_string());
-        if eq(&ch, &'H') {
-            let _ret: Vec<u8> = runtime::call_contract(
-                _to.into_hash().unwrap_or_revert().into(),
-                "on_erc1155_received",
-                runtime_args! {},
-            );
-            runtime::revert(ApiError::from(Error::Erc115RejectedToken2));
+        match to {
+            Key::Account(_) => {}
+            Key::Hash(package_hash) => {
+                let response: u32 = runtime::call_versioned_contract(
+                    ContractPackageHash::new(package_hash),
+                    None,
+                    data::ON_ERC1155_BATCH_RECEIVED,
+                    runtime_args! {
+                        "operator" => operator,
+                        "from" => from,
+                        "ids" => ids,
+                        "amounts" => amounts,
+                        "data" => data
+                    },
+                );
+                if response != data::ERC1155_BATCH_RECEIVED {
+                    runtime::revert(ApiError::from(Error::Erc115RejectedToken2));
+                }
+            }
+            _ => runtime::revert(ApiError::from(Error::InvalidReceiver)),
         }
     }
     fn _as_singleton_array(&self, element: U256) -> Vec<U256> {
@@ -485,16 +724,17 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
         vec
     }
     fn _vec_to_str(&self, vec: Vec<U256>) -> String {
-        let mut str = String::new();
-
-        for i in 0..vec.len() {
-            let elem: String = vec[i].to_string();
-            str.push_str(&elem);
-        }
-        str
+        vec.iter()
+            .map(|elem| elem.to_string())
+            .collect::<Vec<String>>()
+            .join(data::EVENT_LIST_SEPARATOR)
     }
 
     fn erc1155_emit(&mut self, erc1155_event: &ERC1155Event) {
+        if data::events_mode() == EventsMode::CES as u8 {
+            ces::emit(erc1155_event);
+            return;
+        }
         let mut events = Vec::new();
         let package = data::get_package_hash();
         match erc1155_event {
@@ -506,11 +746,12 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
                 amounts,
             } => {
                 let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", package.to_string());
+                event.insert("contract_package_hash", package.to_formatted_string());
                 event.insert("event_type", erc1155_event.type_name());
-                event.insert("operator", operator.to_string());
-                event.insert("from", from.to_string());
-                event.insert("to", to.to_string());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("operator", operator.to_formatted_string());
+                event.insert("from", from.to_formatted_string());
+                event.insert("to", to.to_formatted_string());
                 event.insert("ids", self._vec_to_str(ids.to_vec()));
                 event.insert("amounts", self._vec_to_str(amounts.to_vec()));
                 events.push(event);
@@ -523,11 +764,12 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
                 amount,
             } => {
                 let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", package.to_string());
+                event.insert("contract_package_hash", package.to_formatted_string());
                 event.insert("event_type", erc1155_event.type_name());
-                event.insert("operator", operator.to_string());
-                event.insert("from", from.to_string());
-                event.insert("to", to.to_string());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("operator", operator.to_formatted_string());
+                event.insert("from", from.to_formatted_string());
+                event.insert("to", to.to_formatted_string());
                 event.insert("id", id.to_string());
                 event.insert("amount", amount.to_string());
                 events.push(event);
@@ -536,13 +778,127 @@ pub trait ERC1155<Storage: ContractStorage>: ContractContext<Storage> {
                 owner,
                 operator,
                 approved,
+                expires_at,
             } => {
                 let mut event = BTreeMap::new();
-                event.insert("contract_package_hash", package.to_string());
+                event.insert("contract_package_hash", package.to_formatted_string());
                 event.insert("event_type", erc1155_event.type_name());
-                event.insert("owner", owner.to_string());
-                event.insert("operator", operator.to_string());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("owner", owner.to_formatted_string());
+                event.insert("operator", operator.to_formatted_string());
                 event.insert("approved", approved.to_string());
+                event.insert("expires_at", expires_at.to_string());
+                events.push(event);
+            }
+            ERC1155Event::URI { value, id } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_formatted_string());
+                event.insert("event_type", erc1155_event.type_name());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("value", value.to_string());
+                event.insert("id", id.to_string());
+                events.push(event);
+            }
+            ERC1155Event::BaseURI { value } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_formatted_string());
+                event.insert("event_type", erc1155_event.type_name());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("value", value.to_string());
+                events.push(event);
+            }
+            ERC1155Event::Approval {
+                owner,
+                operator,
+                id,
+                amount,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_formatted_string());
+                event.insert("event_type", erc1155_event.type_name());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("owner", owner.to_formatted_string());
+                event.insert("operator", operator.to_formatted_string());
+                event.insert("id", id.to_string());
+                event.insert("amount", amount.to_string());
+                events.push(event);
+            }
+            ERC1155Event::OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_formatted_string());
+                event.insert("event_type", erc1155_event.type_name());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("previous_owner", previous_owner.to_formatted_string());
+                event.insert("new_owner", new_owner.to_formatted_string());
+                events.push(event);
+            }
+            ERC1155Event::RoleGranted {
+                role,
+                account,
+                sender,
+            }
+            | ERC1155Event::RoleRevoked {
+                role,
+                account,
+                sender,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_formatted_string());
+                event.insert("event_type", erc1155_event.type_name());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("role", role.to_string());
+                event.insert("account", account.to_formatted_string());
+                event.insert("sender", sender.to_formatted_string());
+                events.push(event);
+            }
+            ERC1155Event::RoleAdminChanged {
+                role,
+                previous_admin_role,
+                new_admin_role,
+            } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_formatted_string());
+                event.insert("event_type", erc1155_event.type_name());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("role", role.to_string());
+                event.insert("previous_admin_role", previous_admin_role.to_string());
+                event.insert("new_admin_role", new_admin_role.to_string());
+                events.push(event);
+            }
+            ERC1155Event::Paused { account } | ERC1155Event::Unpaused { account } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_formatted_string());
+                event.insert("event_type", erc1155_event.type_name());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("account", account.to_formatted_string());
+                events.push(event);
+            }
+            ERC1155Event::OperatorFilterModeUpdated { mode } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_formatted_string());
+                event.insert("event_type", erc1155_event.type_name());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("mode", mode.to_string());
+                events.push(event);
+            }
+            ERC1155Event::OperatorFilterUpdated { operator, listed } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_formatted_string());
+                event.insert("event_type", erc1155_event.type_name());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("operator", operator.to_formatted_string());
+                event.insert("listed", listed.to_string());
+                events.push(event);
+            }
+            ERC1155Event::TransfersLocked { id } => {
+                let mut event = BTreeMap::new();
+                event.insert("contract_package_hash", package.to_formatted_string());
+                event.insert("event_type", erc1155_event.type_name());
+                event.insert("event_version", data::EVENT_VERSION.to_string());
+                event.insert("id", id.to_string());
                 events.push(event);
             }
         };
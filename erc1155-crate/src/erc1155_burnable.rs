@@ -0,0 +1,24 @@
+use crate::{env::runtime, erc1155::Error, erc165, ERC1155};
+use alloc::vec::Vec;
+use casper_types::{ApiError, Key, U256};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+pub trait ERC1155Burnable<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage>
+{
+    fn init(&self) {
+        erc165::register_interface(erc165::ERC1155_BURNABLE_INTERFACE_ID);
+    }
+    fn burn(&mut self, from: Key, id: U256, amount: U256) {
+        if !(from == self.get_caller() || self.is_approved_for_all(from, self.get_caller())) {
+            runtime::revert(ApiError::from(Error::NotOwnerNotApproved));
+        }
+        ERC1155::_burn(self, from, id, amount);
+    }
+    fn burn_batch(&mut self, from: Key, ids: Vec<U256>, amounts: Vec<U256>) {
+        if !(from == self.get_caller() || self.is_approved_for_all(from, self.get_caller())) {
+            runtime::revert(ApiError::from(Error::NotOwnerNotApproved));
+        }
+        ERC1155::_burn_batch(self, from, ids, amounts);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    data::{MaxSupplies, ZERO_ADDRESS},
+    env::runtime,
+    erc1155::Error,
+    erc165, ERC1155Supply, ERC1155,
+};
+use casper_types::{ApiError, Key, U256};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+pub trait ERC1155Capped<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage> + ERC1155Supply<Storage>
+{
+    fn init(&self) {
+        MaxSupplies::init();
+        erc165::register_interface(erc165::ERC1155_CAPPED_INTERFACE_ID);
+    }
+    // Zero means the id is uncapped.
+    fn max_supply(&self, id: U256) -> U256 {
+        MaxSupplies::instance().get(&id)
+    }
+    fn remaining_supply(&self, id: U256) -> U256 {
+        let max_supply: U256 = self.max_supply(id);
+        let cap: U256 = if max_supply.is_zero() {
+            U256::MAX
+        } else {
+            max_supply
+        };
+        cap.saturating_sub(self.total_supply(id))
+    }
+    fn is_max_supply_locked(&self, id: U256) -> bool {
+        MaxSupplies::instance().locked(&id)
+    }
+    // Setting zero removes the cap. With `immutable` the cap can never be changed again.
+    fn _set_max_supply(&mut self, id: U256, max_supply: U256, immutable: bool) {
+        let max_supplies = MaxSupplies::instance();
+        if max_supplies.locked(&id) {
+            runtime::revert(ApiError::from(Error::MaxSupplyLocked));
+        }
+        if !max_supply.is_zero() && max_supply < self.total_supply(id) {
+            runtime::revert(ApiError::from(Error::MaxSupplyBelowTotalSupply));
+        }
+        max_supplies.set(&id, max_supply);
+        if immutable {
+            max_supplies.lock(&id);
+        }
+    }
+    // Meant to be called from `_before_token_transfer` of the implementing contract, after
+    // `ERC1155Supply::_update_supply` so repeated ids in a batch are accounted for.
+    fn _enforce_max_supply(&self, from: Key, ids: &[U256]) {
+        if from != ZERO_ADDRESS() {
+            return;
+        }
+        for id in ids {
+            let max_supply: U256 = self.max_supply(*id);
+            if !max_supply.is_zero() && self.total_supply(*id) > max_supply {
+                runtime::revert(ApiError::from(Error::MaxSupplyExceeded));
+            }
+        }
+    }
+}
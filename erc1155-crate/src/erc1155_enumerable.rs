@@ -0,0 +1,106 @@
+use crate::{
+    data::{Holders, OwnedTokens, ZERO_ADDRESS},
+    env::runtime,
+    erc1155::Error,
+    erc165, ERC1155,
+};
+use alloc::vec::Vec;
+use casper_types::{ApiError, Key, U256};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+pub const MAX_PAGE_SIZE: u64 = 100;
+
+pub trait ERC1155Enumerable<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage>
+{
+    fn init(&self) {
+        OwnedTokens::init();
+        Holders::init();
+        erc165::register_interface(erc165::ERC1155_ENUMERABLE_INTERFACE_ID);
+    }
+    fn tokens_of_owner(&self, owner: Key, page: U256, size: U256) -> Vec<U256> {
+        let owned_tokens = OwnedTokens::instance();
+        self._page(owned_tokens.count(&owner), page, size)
+            .map(|index| owned_tokens.get(&owner, index.into()))
+            .collect()
+    }
+    fn holders_of(&self, id: U256, page: U256, size: U256) -> Vec<Key> {
+        let holders = Holders::instance();
+        self._page(holders.count(id), page, size)
+            .map(|index| holders.get(id, index.into()))
+            .collect()
+    }
+    fn token_count(&self, owner: Key) -> U256 {
+        OwnedTokens::instance().count(&owner)
+    }
+    fn holder_count(&self, id: U256) -> U256 {
+        Holders::instance().count(id)
+    }
+    // Meant to be called from `_after_token_transfer` of the implementing contract, once the
+    // balances reflect the transfer.
+    fn _update_enumeration(&mut self, from: Key, to: Key, ids: &[U256]) {
+        for id in ids {
+            if from != ZERO_ADDRESS() && self.balance_of(from, *id).is_zero() {
+                self._remove_holding(from, *id);
+            }
+            if to != ZERO_ADDRESS() && !self.balance_of(to, *id).is_zero() {
+                self._add_holding(to, *id);
+            }
+        }
+    }
+    fn _add_holding(&mut self, owner: Key, id: U256) {
+        let owned_tokens = OwnedTokens::instance();
+        if owned_tokens.position(&owner, id).is_zero() {
+            let count: U256 = owned_tokens.count(&owner);
+            owned_tokens.set(&owner, count, id);
+            owned_tokens.set_position(&owner, id, count + 1);
+            owned_tokens.set_count(&owner, count + 1);
+        }
+        let holders = Holders::instance();
+        if holders.position(id, &owner).is_zero() {
+            let count: U256 = holders.count(id);
+            holders.set(id, count, owner);
+            holders.set_position(id, &owner, count + 1);
+            holders.set_count(id, count + 1);
+        }
+    }
+    // Swaps the last entry into the removed slot so both lists stay dense.
+    fn _remove_holding(&mut self, owner: Key, id: U256) {
+        let owned_tokens = OwnedTokens::instance();
+        let position: U256 = owned_tokens.position(&owner, id);
+        if !position.is_zero() {
+            let last: U256 = owned_tokens.count(&owner) - 1;
+            if position - 1 != last {
+                let last_id: U256 = owned_tokens.get(&owner, last);
+                owned_tokens.set(&owner, position - 1, last_id);
+                owned_tokens.set_position(&owner, last_id, position);
+            }
+            owned_tokens.set_position(&owner, id, U256::zero());
+            owned_tokens.set_count(&owner, last);
+        }
+        let holders = Holders::instance();
+        let position: U256 = holders.position(id, &owner);
+        if !position.is_zero() {
+            let last: U256 = holders.count(id) - 1;
+            if position - 1 != last {
+                let last_holder: Key = holders.get(id, last);
+                holders.set(id, position - 1, last_holder);
+                holders.set_position(id, &last_holder, position);
+            }
+            holders.set_position(id, &owner, U256::zero());
+            holders.set_count(id, last);
+        }
+    }
+    // Indices of the requested page, clipped to `count`.
+    fn _page(&self, count: U256, page: U256, size: U256) -> core::ops::Range<u64> {
+        if size.is_zero() || size > U256::from(MAX_PAGE_SIZE) {
+            runtime::revert(ApiError::from(Error::InvalidPageSize));
+        }
+        let start: U256 = page.saturating_mul(size);
+        if start >= count {
+            return 0..0;
+        }
+        let end: U256 = (start + size).min(count);
+        start.as_u64()..end.as_u64()
+    }
+}
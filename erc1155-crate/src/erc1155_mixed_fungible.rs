@@ -0,0 +1,89 @@
+use crate::{
+    data::{NonFungibleItems, ZERO_ADDRESS},
+    env::runtime,
+    erc1155::Error,
+    erc165, ERC1155,
+};
+use alloc::vec::Vec;
+use casper_types::{bytesrepr::Bytes, ApiError, Key, U256};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+// Split-id layout: the upper 128 bits of an id hold its base type and the lower 128 bits the
+// index of a non-fungible item. The top bit marks non-fungible base types and their items.
+pub const TYPE_NF_BIT: U256 = U256([0, 0, 0, 1 << 63]);
+pub const TYPE_MASK: U256 = U256([0, 0, u64::MAX, u64::MAX]);
+pub const NF_INDEX_MASK: U256 = U256([u64::MAX, u64::MAX, 0, 0]);
+
+pub trait ERC1155MixedFungible<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage>
+{
+    fn init(&self) {
+        NonFungibleItems::init();
+        erc165::register_interface(erc165::ERC1155_MIXED_FUNGIBLE_INTERFACE_ID);
+    }
+    fn is_non_fungible(&self, id: U256) -> bool {
+        id & TYPE_NF_BIT == TYPE_NF_BIT
+    }
+    fn is_non_fungible_base_type(&self, id: U256) -> bool {
+        self.is_non_fungible(id) && self.get_index(id).is_zero()
+    }
+    fn is_non_fungible_item(&self, id: U256) -> bool {
+        self.is_non_fungible(id) && !self.get_index(id).is_zero()
+    }
+    fn get_base_type(&self, id: U256) -> U256 {
+        id & TYPE_MASK
+    }
+    fn get_index(&self, id: U256) -> U256 {
+        id & NF_INDEX_MASK
+    }
+    fn owner_of(&self, id: U256) -> Key {
+        NonFungibleItems::instance().owner(&id)
+    }
+    // Mints one item of `type_id` to each recipient, at the next free indices.
+    fn _mint_non_fungible(&mut self, type_id: U256, recipients: Vec<Key>) -> Vec<U256> {
+        if !self.is_non_fungible_base_type(type_id) {
+            runtime::revert(ApiError::from(Error::NotNonFungibleType));
+        }
+        let items = NonFungibleItems::instance();
+        let mut index: U256 = items.max_index(&type_id);
+        let mut ids: Vec<U256> = Vec::new();
+        for to in recipients {
+            if index == NF_INDEX_MASK {
+                runtime::revert(ApiError::from(Error::InvalidNonFungibleIndex));
+            }
+            index = index + 1;
+            items.set_max_index(&type_id, index);
+            let id: U256 = type_id | index;
+            self._mint(to, id, U256::one(), Bytes::new());
+            ids.push(id);
+        }
+        ids
+    }
+    // Meant to be called from `_before_token_transfer` of the implementing contract. Ids
+    // without the non-fungible bit are left alone.
+    fn _update_non_fungible_owners(&mut self, from: Key, to: Key, ids: &[U256], amounts: &[U256]) {
+        let items = NonFungibleItems::instance();
+        for (id, amount) in ids.iter().zip(amounts.iter()) {
+            if !self.is_non_fungible(*id) {
+                continue;
+            }
+            if !self.is_non_fungible_item(*id) {
+                runtime::revert(ApiError::from(Error::InvalidNonFungibleIndex));
+            }
+            if *amount != U256::one() {
+                runtime::revert(ApiError::from(Error::NonFungibleAmount));
+            }
+            // Items only come into existence through `_mint_non_fungible`, which reserves the
+            // index right before minting it. An item is minted at most once, even after a burn.
+            if from == ZERO_ADDRESS() {
+                if self.get_index(*id) != items.max_index(&self.get_base_type(*id))
+                    || items.minted(id)
+                {
+                    runtime::revert(ApiError::from(Error::InvalidNonFungibleIndex));
+                }
+                items.set_minted(id);
+            }
+            items.set_owner(id, to);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    data::{self, FilteredOperators, ZERO_ADDRESS},
+    env::{runtime, UnwrapOrRevert},
+    erc1155::{ERC1155Event, Error},
+    erc165, Ownable, ERC1155,
+};
+use casper_types::{ApiError, Key};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+use core::convert::TryFrom;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OperatorFilterMode {
+    /// Every operator may be approved and move tokens.
+    Disabled = 0,
+    /// Only listed operators may be approved and move tokens.
+    AllowList = 1,
+    /// Listed operators may neither be approved nor move tokens.
+    DenyList = 2,
+}
+
+impl TryFrom<u8> for OperatorFilterMode {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(OperatorFilterMode::Disabled),
+            1 => Ok(OperatorFilterMode::AllowList),
+            2 => Ok(OperatorFilterMode::DenyList),
+            _ => Err(Error::InvalidOperatorFilterMode),
+        }
+    }
+}
+
+pub trait ERC1155OperatorFilter<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage> + Ownable<Storage>
+{
+    fn init(&self) {
+        FilteredOperators::init();
+        data::set_operator_filter_mode(OperatorFilterMode::Disabled as u8);
+        erc165::register_interface(erc165::ERC1155_OPERATOR_FILTER_INTERFACE_ID);
+    }
+    fn operator_filter_mode(&self) -> OperatorFilterMode {
+        OperatorFilterMode::try_from(data::operator_filter_mode()).unwrap_or_revert()
+    }
+    // The same list serves as allow-list or deny-list depending on the mode.
+    fn is_operator_listed(&self, operator: Key) -> bool {
+        FilteredOperators::instance().get(&operator)
+    }
+    fn is_operator_allowed(&self, operator: Key) -> bool {
+        match self.operator_filter_mode() {
+            OperatorFilterMode::Disabled => true,
+            OperatorFilterMode::AllowList => self.is_operator_listed(operator),
+            OperatorFilterMode::DenyList => !self.is_operator_listed(operator),
+        }
+    }
+    fn set_operator_filter_mode(&mut self, mode: OperatorFilterMode) {
+        self.only_owner();
+        data::set_operator_filter_mode(mode as u8);
+        self.erc1155_emit(&ERC1155Event::OperatorFilterModeUpdated { mode: mode as u8 });
+    }
+    fn set_operator_listed(&mut self, operator: Key, listed: bool) {
+        self.only_owner();
+        FilteredOperators::instance().set(&operator, listed);
+        self.erc1155_emit(&ERC1155Event::OperatorFilterUpdated { operator, listed });
+    }
+    // Meant to be called from `_before_approval_for_all`. Revoking is always possible.
+    fn _filter_approval(&self, operator: Key, approved: bool) {
+        if approved {
+            self._require_operator_allowed(operator);
+        }
+    }
+    // Meant to be called from `_before_token_transfer`. Only transfers and burns made on
+    // behalf of another account are filtered.
+    fn _filter_transfer(&self, operator: Key, from: Key) {
+        if from != ZERO_ADDRESS() && operator != from {
+            self._require_operator_allowed(operator);
+        }
+    }
+    fn _require_operator_allowed(&self, operator: Key) {
+        if !self.is_operator_allowed(operator) {
+            runtime::revert(ApiError::from(Error::OperatorNotAllowed));
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{
+    data::{self, Nonces},
+    env::{runtime, UnwrapOrRevert},
+    erc1155::Error,
+    erc165, ERC1155,
+};
+use alloc::{string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    ApiError, Key, PublicKey, U256,
+};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+use cryptoxide::{digest::Digest, sha2::Sha256};
+use renvm_sig::keccak256;
+
+pub const DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,bytes32 package_hash)";
+pub const DOMAIN_VERSION: &str = "1";
+pub const PERMIT_FOR_ALL_TYPE: &str =
+    "PermitForAll(Key owner,Key operator,bool approved,u64 deadline,U256 nonce)";
+pub const TRANSFER_TYPE: &str =
+    "Transfer(Key from,Key to,U256 id,U256 amount,string data,u64 deadline,U256 nonce)";
+pub const BATCH_TRANSFER_TYPE: &str =
+    "BatchTransfer(Key from,Key to,U256[] ids,U256[] amounts,string data,u64 deadline,U256 nonce)";
+
+pub trait ERC1155Permit<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage>
+{
+    fn init(&self) {
+        Nonces::init();
+        erc165::register_interface(erc165::ERC1155_PERMIT_INTERFACE_ID);
+    }
+    fn nonces(&self, owner: Key) -> U256 {
+        Nonces::instance().get(&owner)
+    }
+    // Binds signatures to this package so they cannot be replayed against another deployment.
+    fn domain_separator(&self) -> [u8; 32] {
+        let mut domain: Vec<u8> = keccak256(DOMAIN_TYPE.as_bytes()).to_vec();
+        domain.extend_from_slice(&keccak256(self.name().as_bytes()));
+        domain.extend_from_slice(&keccak256(DOMAIN_VERSION.as_bytes()));
+        domain.extend_from_slice(&data::get_package_hash().value());
+        keccak256(&domain)
+    }
+    fn permit_for_all_digest(
+        &self,
+        owner: Key,
+        operator: Key,
+        approved: bool,
+        deadline: u64,
+        nonce: U256,
+    ) -> [u8; 32] {
+        let mut encoded: Vec<u8> = Vec::new();
+        encoded.append(&mut owner.to_bytes().unwrap_or_revert());
+        encoded.append(&mut operator.to_bytes().unwrap_or_revert());
+        encoded.append(&mut approved.to_bytes().unwrap_or_revert());
+        encoded.append(&mut deadline.to_bytes().unwrap_or_revert());
+        encoded.append(&mut nonce.to_bytes().unwrap_or_revert());
+        self._hash_typed_data(PERMIT_FOR_ALL_TYPE, &encoded)
+    }
+    #[allow(clippy::too_many_arguments)]
+    fn permit_for_all(
+        &mut self,
+        owner: Key,
+        operator: Key,
+        approved: bool,
+        deadline: u64,
+        nonce: U256,
+        signature: Bytes,
+        public_key: PublicKey,
+    ) {
+        self._require_not_expired(deadline);
+        let digest: [u8; 32] =
+            self.permit_for_all_digest(owner, operator, approved, deadline, nonce);
+        self._require_signed_by(owner, &public_key, &digest, &signature);
+        self._use_nonce(owner, nonce);
+        self._set_approval_for_all(owner, operator, approved);
+    }
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_digest(
+        &self,
+        from: Key,
+        to: Key,
+        id: U256,
+        amount: U256,
+        data: String,
+        deadline: u64,
+        nonce: U256,
+    ) -> [u8; 32] {
+        let mut encoded: Vec<u8> = Vec::new();
+        encoded.append(&mut from.to_bytes().unwrap_or_revert());
+        encoded.append(&mut to.to_bytes().unwrap_or_revert());
+        encoded.append(&mut id.to_bytes().unwrap_or_revert());
+        encoded.append(&mut amount.to_bytes().unwrap_or_revert());
+        encoded.append(&mut data.to_bytes().unwrap_or_revert());
+        encoded.append(&mut deadline.to_bytes().unwrap_or_revert());
+        encoded.append(&mut nonce.to_bytes().unwrap_or_revert());
+        self._hash_typed_data(TRANSFER_TYPE, &encoded)
+    }
+    #[allow(clippy::too_many_arguments)]
+    fn batch_transfer_digest(
+        &self,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        data: String,
+        deadline: u64,
+        nonce: U256,
+    ) -> [u8; 32] {
+        let mut encoded: Vec<u8> = Vec::new();
+        encoded.append(&mut from.to_bytes().unwrap_or_revert());
+        encoded.append(&mut to.to_bytes().unwrap_or_revert());
+        encoded.append(&mut ids.to_bytes().unwrap_or_revert());
+        encoded.append(&mut amounts.to_bytes().unwrap_or_revert());
+        encoded.append(&mut data.to_bytes().unwrap_or_revert());
+        encoded.append(&mut deadline.to_bytes().unwrap_or_revert());
+        encoded.append(&mut nonce.to_bytes().unwrap_or_revert());
+        self._hash_typed_data(BATCH_TRANSFER_TYPE, &encoded)
+    }
+    // Relayed transfer: the caller only pays for the deploy, `from` signs and acts as operator.
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_with_signature(
+        &mut self,
+        from: Key,
+        to: Key,
+        id: U256,
+        amount: U256,
+        data: String,
+        deadline: u64,
+        nonce: U256,
+        signature: Bytes,
+        public_key: PublicKey,
+    ) {
+        self._require_not_expired(deadline);
+        let digest: [u8; 32] =
+            self.transfer_digest(from, to, id, amount, data.clone(), deadline, nonce);
+        self._require_signed_by(from, &public_key, &digest, &signature);
+        self._use_nonce(from, nonce);
+        self._safe_transfer_from(from, from, to, id, amount, Bytes::from(data.as_bytes()));
+    }
+    #[allow(clippy::too_many_arguments)]
+    fn batch_transfer_with_signature(
+        &mut self,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        data: String,
+        deadline: u64,
+        nonce: U256,
+        signature: Bytes,
+        public_key: PublicKey,
+    ) {
+        self._require_not_expired(deadline);
+        let digest: [u8; 32] = self.batch_transfer_digest(
+            from,
+            to,
+            ids.clone(),
+            amounts.clone(),
+            data.clone(),
+            deadline,
+            nonce,
+        );
+        self._require_signed_by(from, &public_key, &digest, &signature);
+        self._use_nonce(from, nonce);
+        self._safe_batch_transfer_from(from, from, to, ids, amounts, Bytes::from(data.as_bytes()));
+    }
+    // EIP-712 style digest of a message of `type_name` whose fields serialize to `encoded`.
+    fn _hash_typed_data(&self, type_name: &str, encoded: &[u8]) -> [u8; 32] {
+        let mut message: Vec<u8> = keccak256(type_name.as_bytes()).to_vec();
+        message.extend_from_slice(encoded);
+        let mut typed_data: Vec<u8> = b"\x19\x01".to_vec();
+        typed_data.extend_from_slice(&self.domain_separator());
+        typed_data.extend_from_slice(&keccak256(&message));
+        keccak256(&typed_data)
+    }
+    // `deadline` is a block time in milliseconds, inclusive.
+    fn _require_not_expired(&self, deadline: u64) {
+        if u64::from(runtime::get_blocktime()) > deadline {
+            runtime::revert(ApiError::from(Error::PermitExpired));
+        }
+    }
+    fn _require_signed_by(
+        &self,
+        signer: Key,
+        public_key: &PublicKey,
+        digest: &[u8],
+        signature: &[u8],
+    ) {
+        if signer != Key::Account(public_key.to_account_hash()) {
+            runtime::revert(ApiError::from(Error::InvalidSigner));
+        }
+        if !verify_signature(public_key, digest, signature) {
+            runtime::revert(ApiError::from(Error::InvalidSignature));
+        }
+    }
+    fn _use_nonce(&mut self, owner: Key, nonce: U256) {
+        let nonces = Nonces::instance();
+        let current: U256 = nonces.get(&owner);
+        if nonce != current {
+            runtime::revert(ApiError::from(Error::InvalidNonce));
+        }
+        nonces.set(&owner, current + 1);
+    }
+}
+
+// Checks a raw 64 byte Ed25519 or secp256k1 signature of `message`. Secp256k1 signatures are
+// over the SHA-256 digest of the message, as produced by casper signing tools.
+pub fn verify_signature(public_key: &PublicKey, message: &[u8], signature: &[u8]) -> bool {
+    let key: Vec<u8> = public_key.into();
+    match public_key {
+        PublicKey::Ed25519(_) => {
+            signature.len() == cryptoxide::ed25519::SIGNATURE_LENGTH
+                && cryptoxide::ed25519::verify(message, &key, signature)
+        }
+        PublicKey::Secp256k1(_) => {
+            let mut digest = [0u8; 32];
+            let mut hasher = Sha256::new();
+            hasher.input(message);
+            hasher.result(&mut digest);
+            let message = secp256k1::Message::parse(&digest);
+            let signature = match secp256k1::Signature::parse_slice(signature) {
+                Ok(signature) => signature,
+                Err(_) => return false,
+            };
+            match secp256k1::PublicKey::parse_slice(&key, None) {
+                Ok(key) => secp256k1::verify(&message, &signature, &key),
+                Err(_) => false,
+            }
+        }
+        PublicKey::System => false,
+    }
+}
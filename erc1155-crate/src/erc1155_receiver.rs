@@ -0,0 +1,99 @@
+use crate::{data, erc165};
+use alloc::{boxed::Box, vec, vec::Vec};
+use casper_types::{
+    bytesrepr::Bytes, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    Key, Parameter, U256,
+};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReceiverPolicy {
+    AcceptAll,
+    RejectAll,
+}
+
+pub trait ERC1155Receiver<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&self) {
+        erc165::register_interface(erc165::ERC1155_RECEIVER_INTERFACE_ID);
+    }
+    fn receiver_policy(&self) -> ReceiverPolicy {
+        ReceiverPolicy::AcceptAll
+    }
+    fn on_erc1155_received(
+        &mut self,
+        operator: Key,
+        from: Key,
+        id: U256,
+        amount: U256,
+        data: Bytes,
+    ) -> u32 {
+        if self._on_erc1155_received(operator, from, id, amount, data) {
+            data::ERC1155_RECEIVED
+        } else {
+            0
+        }
+    }
+    fn on_erc1155_batch_received(
+        &mut self,
+        operator: Key,
+        from: Key,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        data: Bytes,
+    ) -> u32 {
+        if self._on_erc1155_batch_received(operator, from, ids, amounts, data) {
+            data::ERC1155_BATCH_RECEIVED
+        } else {
+            0
+        }
+    }
+    fn _on_erc1155_received(
+        &mut self,
+        _operator: Key,
+        _from: Key,
+        _id: U256,
+        _amount: U256,
+        _data: Bytes,
+    ) -> bool {
+        self.receiver_policy() == ReceiverPolicy::AcceptAll
+    }
+    fn _on_erc1155_batch_received(
+        &mut self,
+        _operator: Key,
+        _from: Key,
+        _ids: Vec<U256>,
+        _amounts: Vec<U256>,
+        _data: Bytes,
+    ) -> bool {
+        self.receiver_policy() == ReceiverPolicy::AcceptAll
+    }
+}
+
+pub fn add_receiver_entry_points(entry_points: &mut EntryPoints) {
+    entry_points.add_entry_point(EntryPoint::new(
+        data::ON_ERC1155_RECEIVED,
+        vec![
+            Parameter::new("operator", Key::cl_type()),
+            Parameter::new("from", Key::cl_type()),
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+            Parameter::new("data", Bytes::cl_type()),
+        ],
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        data::ON_ERC1155_BATCH_RECEIVED,
+        vec![
+            Parameter::new("operator", Key::cl_type()),
+            Parameter::new("from", Key::cl_type()),
+            Parameter::new("ids", CLType::List(Box::new(CLType::U256))),
+            Parameter::new("amounts", CLType::List(Box::new(CLType::U256))),
+            Parameter::new("data", Bytes::cl_type()),
+        ],
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+}
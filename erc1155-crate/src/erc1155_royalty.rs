@@ -0,0 +1,57 @@
+use crate::{
+    data::{self, Royalties, ZERO_ADDRESS},
+    env::runtime,
+    erc1155::Error,
+    erc165, ERC1155,
+};
+use casper_types::{ApiError, Key, U256};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+pub const FEE_DENOMINATOR: u64 = 10000;
+
+pub trait ERC1155Royalty<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage>
+{
+    fn init(&self) {
+        Royalties::init();
+        data::set_default_royalty(ZERO_ADDRESS(), U256::zero());
+        erc165::register_interface(erc165::ERC2981_INTERFACE_ID);
+    }
+    // Receiver and amount owed for a sale of `id` at `sale_price`, in the unit of the price.
+    fn royalty_info(&self, id: U256, sale_price: U256) -> (Key, U256) {
+        let (mut receiver, mut fraction) = Royalties::instance().get(&id);
+        if receiver == ZERO_ADDRESS() {
+            let (default_receiver, default_fraction) = data::default_royalty();
+            receiver = default_receiver;
+            fraction = default_fraction;
+        }
+        // Split the price so the product cannot overflow for any sale price.
+        let denominator: U256 = U256::from(FEE_DENOMINATOR);
+        let amount: U256 =
+            sale_price / denominator * fraction + sale_price % denominator * fraction / denominator;
+        (receiver, amount)
+    }
+    fn _set_default_royalty(&mut self, receiver: Key, fraction: U256) {
+        self._validate_royalty(receiver, fraction);
+        data::set_default_royalty(receiver, fraction);
+    }
+    fn _delete_default_royalty(&mut self) {
+        data::set_default_royalty(ZERO_ADDRESS(), U256::zero());
+    }
+    fn _set_token_royalty(&mut self, id: U256, receiver: Key, fraction: U256) {
+        self._validate_royalty(receiver, fraction);
+        Royalties::instance().set(&id, receiver, fraction);
+    }
+    // Falls back to the default royalty.
+    fn _reset_token_royalty(&mut self, id: U256) {
+        Royalties::instance().set(&id, ZERO_ADDRESS(), U256::zero());
+    }
+    fn _validate_royalty(&self, receiver: Key, fraction: U256) {
+        if fraction > U256::from(FEE_DENOMINATOR) {
+            runtime::revert(ApiError::from(Error::InvalidRoyaltyFraction));
+        }
+        if receiver == ZERO_ADDRESS() {
+            runtime::revert(ApiError::from(Error::InvalidRoyaltyReceiver));
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{
+    data::{NonTransferable, ZERO_ADDRESS},
+    env::runtime,
+    erc1155::{ERC1155Event, Error},
+    erc165, ERC1155,
+};
+use casper_types::{ApiError, Key, U256};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+pub trait ERC1155Soulbound<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage>
+{
+    fn init(&self) {
+        NonTransferable::init();
+        erc165::register_interface(erc165::ERC1155_SOULBOUND_INTERFACE_ID);
+    }
+    // Ids are transferable unless locked, including unregistered and split ids.
+    fn is_transferable(&self, id: U256) -> bool {
+        !NonTransferable::instance().locked(&id)
+    }
+    // Makes an id soulbound. There is no way back, so holders can rely on it.
+    fn _lock_transfers(&mut self, id: U256) {
+        NonTransferable::instance().lock(&id);
+        self.erc1155_emit(&ERC1155Event::TransfersLocked { id });
+    }
+    // Meant to be called from `_before_token_transfer` with every id, mints and burns excepted.
+    fn _enforce_transferable(&self, from: Key, to: Key, ids: &[U256]) {
+        if from == ZERO_ADDRESS() || to == ZERO_ADDRESS() {
+            return;
+        }
+        if ids.iter().any(|id| !self.is_transferable(*id)) {
+            runtime::revert(ApiError::from(Error::NonTransferable));
+        }
+    }
+}
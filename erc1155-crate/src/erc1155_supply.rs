@@ -0,0 +1,56 @@
+use crate::{
+    data::{self, TotalSupply, ZERO_ADDRESS},
+    env::UnwrapOrRevert,
+    erc1155::Error,
+    erc165, ERC1155,
+};
+use casper_types::{Key, U256};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+pub trait ERC1155Supply<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage>
+{
+    fn init(&self) {
+        TotalSupply::init();
+        data::set_total_supply_all(U256::zero());
+        erc165::register_interface(erc165::ERC1155_SUPPLY_INTERFACE_ID);
+    }
+    fn total_supply(&self, id: U256) -> U256 {
+        TotalSupply::instance().get(&id)
+    }
+    fn total_supply_all(&self) -> U256 {
+        data::total_supply_all()
+    }
+    fn exists(&self, id: U256) -> bool {
+        self.total_supply(id) > U256::zero()
+    }
+    // Meant to be called from `_before_token_transfer` of the implementing contract.
+    fn _update_supply(&mut self, from: Key, to: Key, ids: &[U256], amounts: &[U256]) {
+        if from != ZERO_ADDRESS() && to != ZERO_ADDRESS() {
+            return;
+        }
+        let total_supply = TotalSupply::instance();
+        let mut total_supply_all: U256 = data::total_supply_all();
+        for (id, amount) in ids.iter().zip(amounts.iter()) {
+            let supply: U256 = total_supply.get(id);
+            if from == ZERO_ADDRESS() {
+                let updated_supply: U256 = supply
+                    .checked_add(*amount)
+                    .unwrap_or_revert_with(Error::SupplyOverflow);
+                total_supply.set(id, updated_supply);
+                total_supply_all = total_supply_all
+                    .checked_add(*amount)
+                    .unwrap_or_revert_with(Error::SupplyOverflow);
+            } else {
+                let updated_supply: U256 = supply
+                    .checked_sub(*amount)
+                    .unwrap_or_revert_with(Error::BurnAmountExceedsBal);
+                total_supply.set(id, updated_supply);
+                total_supply_all = total_supply_all
+                    .checked_sub(*amount)
+                    .unwrap_or_revert_with(Error::BurnAmountExceedsBal);
+            }
+        }
+        data::set_total_supply_all(total_supply_all);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    data::{self, TokenTypes, ZERO_ADDRESS},
+    env::{runtime, UnwrapOrRevert},
+    erc1155::Error,
+    erc165, ERC1155Capped, ERC1155Soulbound, ERC1155URIStorage, ERC1155,
+};
+use alloc::string::String;
+use casper_types::{ApiError, Key, U256};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+use core::convert::TryFrom;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    Fungible = 0,
+    NonFungible = 1,
+}
+
+impl TryFrom<u8> for TokenKind {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TokenKind::Fungible),
+            1 => Ok(TokenKind::NonFungible),
+            _ => Err(Error::InvalidTokenKind),
+        }
+    }
+}
+
+pub trait ERC1155TokenTypes<Storage: ContractStorage>:
+    ContractContext<Storage>
+    + ERC1155<Storage>
+    + ERC1155Capped<Storage>
+    + ERC1155Soulbound<Storage>
+    + ERC1155URIStorage<Storage>
+{
+    fn init(&self) {
+        TokenTypes::init();
+        data::set_token_type_count(U256::zero());
+        erc165::register_interface(erc165::ERC1155_TOKEN_TYPES_INTERFACE_ID);
+    }
+    fn token_kind(&self, id: U256) -> Option<TokenKind> {
+        TokenTypes::instance()
+            .kind(&id)
+            .map(|kind| TokenKind::try_from(kind).unwrap_or_revert())
+    }
+    fn token_type_count(&self) -> U256 {
+        data::token_type_count()
+    }
+    // Registers the next id. Non-fungible ids get an immutable cap of one unit and
+    // `_enforce_token_types` only lets them be minted once, so a burned one stays burned.
+    fn _create_token_type(
+        &mut self,
+        kind: TokenKind,
+        metadata_uri: String,
+        max_supply: U256,
+        transferable: bool,
+    ) -> U256 {
+        let id: U256 = data::token_type_count() + 1;
+        let token_types = TokenTypes::instance();
+        token_types.set_kind(&id, kind as u8);
+        data::set_token_type_count(id);
+        if !transferable {
+            self._lock_transfers(id);
+        }
+        match kind {
+            TokenKind::Fungible => {
+                if !max_supply.is_zero() {
+                    ERC1155Capped::_set_max_supply(self, id, max_supply, false);
+                }
+            }
+            TokenKind::NonFungible => {
+                if max_supply > U256::one() {
+                    runtime::revert(ApiError::from(Error::NonFungibleAmount));
+                }
+                ERC1155Capped::_set_max_supply(self, id, U256::one(), true);
+            }
+        }
+        if !metadata_uri.is_empty() {
+            ERC1155URIStorage::_set_token_uri(self, id, metadata_uri);
+        }
+        id
+    }
+    // Meant to be called from `_before_token_transfer` of the implementing contract.
+    fn _enforce_token_types(&self, from: Key, ids: &[U256], amounts: &[U256]) {
+        let token_types = TokenTypes::instance();
+        for (id, amount) in ids.iter().zip(amounts.iter()) {
+            let kind: TokenKind = self
+                .token_kind(*id)
+                .unwrap_or_else(|| runtime::revert(ApiError::from(Error::UnregisteredTokenType)));
+            if from == ZERO_ADDRESS() && kind == TokenKind::NonFungible {
+                if *amount != U256::one() {
+                    runtime::revert(ApiError::from(Error::NonFungibleAmount));
+                }
+                if token_types.minted(id) {
+                    runtime::revert(ApiError::from(Error::NonFungibleAlreadyMinted));
+                }
+                token_types.set_minted(id);
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    data::{self, TokenUris},
+    env::UnwrapOrRevert,
+    erc1155::ERC1155Event,
+    erc165, ERC1155,
+};
+use alloc::string::String;
+use casper_types::U256;
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+pub const ID_SUBSTITUTION: &str = "{id}";
+
+pub trait ERC1155URIStorage<Storage: ContractStorage>:
+    ContractContext<Storage> + ERC1155<Storage>
+{
+    fn init(&self) {
+        TokenUris::init();
+        erc165::register_interface(erc165::ERC1155_URI_STORAGE_INTERFACE_ID);
+    }
+    // Returns the uri set for `id`, falling back to the base uri with `{id}` replaced by
+    // the lowercase hex id padded to 64 characters, as specified by EIP-1155.
+    fn uri(&self, id: U256) -> String {
+        let token_uri: String = TokenUris::instance().get(&id);
+        if !token_uri.is_empty() {
+            return token_uri;
+        }
+        data::uri().replace(ID_SUBSTITUTION, &self._hex_id(id))
+    }
+    // The base uri covers every id without its own uri, so the change is announced once with
+    // the `{id}` template rather than with a `URI` event per id.
+    fn _set_uri(&mut self, uri: String) {
+        data::set_uri(uri.clone());
+        self.erc1155_emit(&ERC1155Event::BaseURI { value: uri });
+    }
+    fn _set_token_uri(&mut self, id: U256, token_uri: String) {
+        TokenUris::instance().set(&id, token_uri);
+        self.erc1155_emit(&ERC1155Event::URI {
+            value: ERC1155URIStorage::uri(self, id),
+            id,
+        });
+    }
+    fn _hex_id(&self, id: U256) -> String {
+        let mut bytes = [0u8; 32];
+        id.to_big_endian(&mut bytes);
+        let mut hex_id = [0u8; 64];
+        hex::encode_to_slice(bytes, &mut hex_id)
+            .ok()
+            .unwrap_or_revert();
+        hex_id.iter().map(|byte| *byte as char).collect()
+    }
+}
@@ -0,0 +1,35 @@
+use crate::data;
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+// Standards with an EIP-165 interface id keep it. Extensions without one use the first four
+// bytes of the blake2b-256 hash of their trait name.
+pub const ERC165_INTERFACE_ID: u32 = 0x01ffc9a7;
+pub const ERC1155_INTERFACE_ID: u32 = 0xd9b67a26;
+pub const ERC1155_METADATA_URI_INTERFACE_ID: u32 = 0x0e89341c;
+pub const ERC1155_RECEIVER_INTERFACE_ID: u32 = 0x4e2312e0;
+pub const ERC2981_INTERFACE_ID: u32 = 0x2a55205a;
+pub const ERC1155_SUPPLY_INTERFACE_ID: u32 = 0xe5525b3c;
+pub const ERC1155_BURNABLE_INTERFACE_ID: u32 = 0xfb491b7e;
+pub const ERC1155_URI_STORAGE_INTERFACE_ID: u32 = 0x198cc804;
+pub const ERC1155_ENUMERABLE_INTERFACE_ID: u32 = 0x68243ce1;
+pub const ERC1155_CAPPED_INTERFACE_ID: u32 = 0x130ba51f;
+pub const ERC1155_TOKEN_TYPES_INTERFACE_ID: u32 = 0x973a1663;
+pub const ERC1155_MIXED_FUNGIBLE_INTERFACE_ID: u32 = 0xcefe3c67;
+pub const ERC1155_PERMIT_INTERFACE_ID: u32 = 0x01594706;
+pub const ERC1155_OPERATOR_FILTER_INTERFACE_ID: u32 = 0x708d614c;
+pub const ERC1155_SOULBOUND_INTERFACE_ID: u32 = 0xf3c202bd;
+
+pub trait ERC165<Storage: ContractStorage>: ContractContext<Storage> {
+    fn supports_interface(&self, interface_id: u32) -> bool {
+        interface_id == ERC165_INTERFACE_ID || data::supported_interfaces().contains(&interface_id)
+    }
+}
+
+// Called from the `init` of each extension. Registering an id twice has no effect.
+pub fn register_interface(interface_id: u32) {
+    let mut interfaces = data::supported_interfaces();
+    if !interfaces.contains(&interface_id) {
+        interfaces.push(interface_id);
+        data::set_supported_interfaces(interfaces);
+    }
+}
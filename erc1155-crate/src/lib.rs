@@ -1,7 +1,45 @@
 #![no_std]
 extern crate alloc;
+#[cfg(feature = "test-runtime")]
+extern crate std;
 
+pub mod access_control;
+pub mod ces;
 pub mod data;
+mod env;
 mod erc1155;
+mod erc1155_burnable;
+mod erc1155_capped;
+mod erc1155_enumerable;
+mod erc1155_mixed_fungible;
+mod erc1155_operator_filter;
+mod erc1155_permit;
+mod erc1155_receiver;
+mod erc1155_royalty;
+mod erc1155_soulbound;
+mod erc1155_supply;
+mod erc1155_token_types;
+mod erc1155_uri_storage;
+pub mod erc165;
+mod ownable;
+mod pausable;
+#[cfg(feature = "test-runtime")]
+pub mod testing;
 
-pub use erc1155::ERC1155;
+pub use access_control::AccessControl;
+pub use erc1155::{ERC1155Event, Error, ERC1155};
+pub use erc1155_burnable::ERC1155Burnable;
+pub use erc1155_capped::ERC1155Capped;
+pub use erc1155_enumerable::ERC1155Enumerable;
+pub use erc1155_mixed_fungible::{ERC1155MixedFungible, NF_INDEX_MASK, TYPE_MASK, TYPE_NF_BIT};
+pub use erc1155_operator_filter::{ERC1155OperatorFilter, OperatorFilterMode};
+pub use erc1155_permit::{verify_signature, ERC1155Permit};
+pub use erc1155_receiver::{add_receiver_entry_points, ERC1155Receiver, ReceiverPolicy};
+pub use erc1155_royalty::ERC1155Royalty;
+pub use erc1155_soulbound::ERC1155Soulbound;
+pub use erc1155_supply::ERC1155Supply;
+pub use erc1155_token_types::{ERC1155TokenTypes, TokenKind};
+pub use erc1155_uri_storage::ERC1155URIStorage;
+pub use erc165::ERC165;
+pub use ownable::Ownable;
+pub use pausable::Pausable;
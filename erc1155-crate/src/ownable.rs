@@ -0,0 +1,45 @@
+use crate::{
+    data::{self, ZERO_ADDRESS},
+    env::runtime,
+    erc1155::{ERC1155Event, Error},
+    ERC1155,
+};
+use casper_types::{ApiError, Key};
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+pub trait Ownable<Storage: ContractStorage>: ContractContext<Storage> + ERC1155<Storage> {
+    fn init(&mut self, owner: Key) {
+        self._transfer_ownership(owner);
+    }
+    fn owner(&self) -> Key {
+        data::owner()
+    }
+    fn only_owner(&self) {
+        if self.get_caller() != self.owner() {
+            runtime::revert(ApiError::from(Error::CallerIsNotOwner));
+        }
+    }
+    fn transfer_ownership(&mut self, new_owner: Key) {
+        self.only_owner();
+        if new_owner == ZERO_ADDRESS() {
+            runtime::revert(ApiError::from(Error::NewOwnerIsZeroAddress));
+        }
+        self._transfer_ownership(new_owner);
+    }
+    // Leaves the contract without an owner. Only `only_owner` checks are disabled here, powers
+    // tied to the owner elsewhere are released through `_after_ownership_transfer`.
+    fn renounce_ownership(&mut self) {
+        self.only_owner();
+        self._transfer_ownership(ZERO_ADDRESS());
+    }
+    fn _transfer_ownership(&mut self, new_owner: Key) {
+        let previous_owner: Key = data::owner();
+        data::set_owner(new_owner);
+        self.erc1155_emit(&ERC1155Event::OwnershipTransferred {
+            previous_owner,
+            new_owner,
+        });
+        self._after_ownership_transfer(previous_owner, new_owner);
+    }
+    fn _after_ownership_transfer(&mut self, _previous_owner: Key, _new_owner: Key) {}
+}
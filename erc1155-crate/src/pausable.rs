@@ -0,0 +1,43 @@
+use crate::{
+    data,
+    env::runtime,
+    erc1155::{ERC1155Event, Error},
+    ERC1155,
+};
+use casper_types::ApiError;
+use casperlabs_contract_utils::{ContractContext, ContractStorage};
+
+pub trait Pausable<Storage: ContractStorage>: ContractContext<Storage> + ERC1155<Storage> {
+    fn init(&mut self) {
+        data::set_paused(false);
+    }
+    fn paused(&self) -> bool {
+        data::paused()
+    }
+    // Meant to be called from `_before_token_transfer` so that transfers, mints and burns
+    // all revert while the contract is paused.
+    fn _require_not_paused(&self) {
+        if self.paused() {
+            runtime::revert(ApiError::from(Error::Paused));
+        }
+    }
+    fn _require_paused(&self) {
+        if !self.paused() {
+            runtime::revert(ApiError::from(Error::NotPaused));
+        }
+    }
+    fn _pause(&mut self) {
+        self._require_not_paused();
+        data::set_paused(true);
+        self.erc1155_emit(&ERC1155Event::Paused {
+            account: self.get_caller(),
+        });
+    }
+    fn _unpause(&mut self) {
+        self._require_paused();
+        data::set_paused(false);
+        self.erc1155_emit(&ERC1155Event::Unpaused {
+            account: self.get_caller(),
+        });
+    }
+}
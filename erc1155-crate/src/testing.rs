@@ -0,0 +1,327 @@
+//! In-memory stand-ins for the casper host functions, enabled with the `test-runtime` feature
+//! so the ERC1155 traits can be exercised natively with `cargo test`.
+//!
+//! State is kept per thread, so every test gets a fresh environment as long as it calls
+//! [`reset`] (or runs on its own thread, which is the default for the test harness).
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    rc::Rc,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    system::CallStackElement,
+    AccessRights, ApiError, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, RuntimeArgs,
+    URef,
+};
+use casperlabs_contract_utils::ContractStorage;
+use core::cell::RefCell;
+use std::{
+    panic::{self, AssertUnwindSafe},
+    thread_local,
+};
+
+/// Package hash the contract under test runs as, unless changed with [`set_contract`].
+pub const CONTRACT_PACKAGE_HASH: [u8; 32] = [0xff; 32];
+
+type ContractHandler = Rc<dyn Fn(&str, RuntimeArgs) -> CLValue>;
+
+#[derive(Default)]
+struct State {
+    named_keys: BTreeMap<String, CLValue>,
+    dictionaries: BTreeMap<String, BTreeMap<String, CLValue>>,
+    urefs: Vec<CLValue>,
+    contracts: BTreeMap<[u8; 32], ContractHandler>,
+    call_stack: Vec<CallStackElement>,
+    contract: Option<ContractPackageHash>,
+    blocktime: u64,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+    // Every distinct call stack handed out by `InMemoryContractStorage`, leaked once so it can
+    // be borrowed for as long as the storage.
+    static CALL_STACKS: RefCell<Vec<&'static [CallStackElement]>> = RefCell::new(Vec::new());
+}
+
+fn with_state<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    STATE.with(|state| f(&mut state.borrow_mut()))
+}
+
+fn to_element(key: Key) -> CallStackElement {
+    match key {
+        Key::Account(account_hash) => CallStackElement::Session { account_hash },
+        Key::Hash(hash) => CallStackElement::StoredContract {
+            contract_package_hash: ContractPackageHash::new(hash),
+            contract_hash: ContractHash::new(hash),
+        },
+        _ => panic!("caller must be an account or a contract package"),
+    }
+}
+
+fn to_cl_value<T: CLTyped + ToBytes>(value: T) -> CLValue {
+    CLValue::from_t(value).expect("value should serialize")
+}
+
+fn from_cl_value<T: CLTyped + FromBytes>(value: &CLValue) -> T {
+    value
+        .clone()
+        .into_t()
+        .expect("stored value has a different type")
+}
+
+/// Clears all named keys, dictionaries, events and registered contracts.
+pub fn reset() {
+    with_state(|state| *state = State::default());
+}
+
+/// Makes `contract` the package hash of the contract under test.
+pub fn set_contract(contract: ContractPackageHash) {
+    with_state(|state| state.contract = Some(contract));
+}
+
+/// Package hash of the contract under test.
+pub fn contract() -> ContractPackageHash {
+    with_state(|state| state.contract)
+        .unwrap_or_else(|| ContractPackageHash::new(CONTRACT_PACKAGE_HASH))
+}
+
+/// Sets the account or contract calling into the contract under test.
+pub fn set_caller(caller: Key) {
+    let contract = contract();
+    with_state(|state| {
+        state.call_stack = vec![to_element(caller), to_element(Key::Hash(contract.value()))]
+    });
+}
+
+/// Sets the block time returned by `runtime::get_blocktime`.
+pub fn set_blocktime(blocktime: u64) {
+    with_state(|state| state.blocktime = blocktime);
+}
+
+/// Registers `handler` to serve `runtime::call_versioned_contract` for `package_hash`.
+pub fn register_contract(
+    package_hash: ContractPackageHash,
+    handler: impl Fn(&str, RuntimeArgs) -> CLValue + 'static,
+) {
+    with_state(|state| {
+        state
+            .contracts
+            .insert(package_hash.value(), Rc::new(handler))
+    });
+}
+
+/// Values written with `storage::new_uref`, oldest first.
+pub fn uref_values() -> Vec<CLValue> {
+    with_state(|state| state.urefs.clone())
+}
+
+/// Runs `f` and returns the error it reverted with, if any.
+pub fn catch_revert<R>(f: impl FnOnce() -> R) -> Result<R, ApiError> {
+    let call_stack = with_state(|state| state.call_stack.clone());
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    with_state(|state| state.call_stack = call_stack);
+    result.map_err(|payload| match payload.downcast::<ApiError>() {
+        Ok(error) => *error,
+        Err(payload) => panic::resume_unwind(payload),
+    })
+}
+
+pub mod runtime {
+    use super::*;
+    use casper_types::ContractVersion;
+
+    pub fn revert<T: Into<ApiError>>(error: T) -> ! {
+        panic::panic_any(error.into())
+    }
+
+    pub fn get_blocktime() -> casper_types::BlockTime {
+        casper_types::BlockTime::new(with_state(|state| state.blocktime))
+    }
+
+    pub fn call_versioned_contract<T: CLTyped + FromBytes>(
+        contract_package_hash: ContractPackageHash,
+        _contract_version: Option<ContractVersion>,
+        entry_point_name: &str,
+        runtime_args: RuntimeArgs,
+    ) -> T {
+        let handler =
+            with_state(|state| state.contracts.get(&contract_package_hash.value()).cloned())
+                .unwrap_or_else(|| revert(ApiError::InvalidArgument));
+        with_state(|state| {
+            state
+                .call_stack
+                .push(to_element(Key::Hash(contract_package_hash.value())))
+        });
+        let ret = handler(entry_point_name, runtime_args);
+        with_state(|state| state.call_stack.pop());
+        from_cl_value(&ret)
+    }
+}
+
+pub mod storage {
+    use super::*;
+
+    pub fn new_uref<T: CLTyped + ToBytes>(init: T) -> URef {
+        with_state(|state| {
+            state.urefs.push(to_cl_value(init));
+            let mut address = [0u8; 32];
+            address[..8].copy_from_slice(&(state.urefs.len() as u64).to_le_bytes());
+            URef::new(address, AccessRights::READ_ADD_WRITE)
+        })
+    }
+}
+
+pub fn get_key<T: FromBytes + CLTyped>(name: &str) -> Option<T> {
+    with_state(|state| state.named_keys.get(name).map(from_cl_value))
+}
+
+pub fn set_key<T: ToBytes + CLTyped>(name: &str, value: T) {
+    let value = to_cl_value(value);
+    with_state(|state| state.named_keys.insert(name.to_string(), value));
+}
+
+pub struct Dict {
+    name: String,
+}
+
+impl Dict {
+    pub fn instance(name: &str) -> Dict {
+        if !with_state(|state| state.dictionaries.contains_key(name)) {
+            runtime::revert(ApiError::MissingKey);
+        }
+        Dict {
+            name: name.to_string(),
+        }
+    }
+
+    pub fn init(name: &str) {
+        with_state(|state| state.dictionaries.entry(name.to_string()).or_default());
+    }
+
+    pub fn get<T: CLTyped + FromBytes>(&self, key: &str) -> Option<T> {
+        with_state(|state| state.dictionaries[&self.name].get(key).map(from_cl_value))
+    }
+
+    pub fn set<T: CLTyped + ToBytes>(&self, key: &str, value: T) {
+        let value = to_cl_value(value);
+        with_state(|state| {
+            state
+                .dictionaries
+                .get_mut(&self.name)
+                .unwrap()
+                .insert(key.to_string(), value)
+        });
+    }
+
+    pub fn get_by_key<T: CLTyped + FromBytes>(&self, key: &Key) -> Option<T> {
+        self.get(&Self::encode(&[key]))
+    }
+
+    pub fn set_by_key<T: CLTyped + ToBytes>(&self, key: &Key, value: T) {
+        self.set(&Self::encode(&[key]), value)
+    }
+
+    pub fn get_by_keys<T: CLTyped + FromBytes, U: CLTyped + ToBytes>(
+        &self,
+        keys: (&U, &Key),
+    ) -> Option<T> {
+        self.get(&Self::encode(&[keys.0, keys.1]))
+    }
+
+    pub fn set_by_keys<T: CLTyped + ToBytes, U: CLTyped + ToBytes>(
+        &self,
+        keys: (&U, &Key),
+        value: T,
+    ) {
+        self.set(&Self::encode(&[keys.0, keys.1]), value)
+    }
+
+    pub fn get_by_values<T: CLTyped + ToBytes, U: CLTyped + ToBytes, R: CLTyped + FromBytes>(
+        &self,
+        keys: (&T, &U),
+    ) -> Option<R> {
+        self.get(&Self::encode(&[keys.0, keys.1]))
+    }
+
+    pub fn set_by_values<T: CLTyped + ToBytes, U: CLTyped + ToBytes, V: CLTyped + ToBytes>(
+        &self,
+        keys: (&T, &U),
+        value: V,
+    ) {
+        self.set(&Self::encode(&[keys.0, keys.1]), value)
+    }
+
+    pub fn remove<T: CLTyped + ToBytes>(&self, key: &str) {
+        with_state(|state| state.dictionaries.get_mut(&self.name).unwrap().remove(key));
+    }
+
+    pub fn remove_by_key<T: CLTyped + ToBytes>(&self, key: &Key) {
+        self.remove::<T>(&Self::encode(&[key]))
+    }
+
+    // On chain the concatenated bytes are hashed, the in-memory store keeps them as-is.
+    fn encode(keys: &[&dyn ToBytesDyn]) -> String {
+        keys.iter()
+            .flat_map(|key| key.to_bytes_dyn())
+            .map(|byte| std::format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+trait ToBytesDyn {
+    fn to_bytes_dyn(&self) -> Vec<u8>;
+}
+
+impl<T: ToBytes> ToBytesDyn for T {
+    fn to_bytes_dyn(&self) -> Vec<u8> {
+        self.to_bytes().expect("key should serialize")
+    }
+}
+
+pub trait UnwrapOrRevert<T> {
+    fn unwrap_or_revert(self) -> T;
+    fn unwrap_or_revert_with<E: Into<ApiError>>(self, error: E) -> T;
+}
+
+impl<T> UnwrapOrRevert<T> for Option<T> {
+    fn unwrap_or_revert(self) -> T {
+        self.unwrap_or_else(|| runtime::revert(ApiError::None))
+    }
+    fn unwrap_or_revert_with<E: Into<ApiError>>(self, error: E) -> T {
+        self.unwrap_or_else(|| runtime::revert(error))
+    }
+}
+
+impl<T, E: Into<ApiError>> UnwrapOrRevert<T> for Result<T, E> {
+    fn unwrap_or_revert(self) -> T {
+        self.unwrap_or_else(|error| runtime::revert(error))
+    }
+    fn unwrap_or_revert_with<F: Into<ApiError>>(self, error: F) -> T {
+        self.unwrap_or_else(|_| runtime::revert(error))
+    }
+}
+
+/// `ContractStorage` backed by the in-memory environment. The call stack is read when it is
+/// asked for, so the default `ContractContext::get_caller` follows [`set_caller`] and nested
+/// calls, and a contract instance can be reused across callers.
+#[derive(Default)]
+pub struct InMemoryContractStorage;
+
+impl ContractStorage for InMemoryContractStorage {
+    fn call_stack(&self) -> &[CallStackElement] {
+        let call_stack = with_state(|state| state.call_stack.clone());
+        CALL_STACKS.with(|stacks| {
+            let mut stacks = stacks.borrow_mut();
+            if let Some(stack) = stacks.iter().find(|stack| **stack == call_stack[..]) {
+                return *stack;
+            }
+            let stack: &'static [CallStackElement] = Box::leak(call_stack.into_boxed_slice());
+            stacks.push(stack);
+            stack
+        })
+    }
+}
@@ -0,0 +1,286 @@
+//! Host-side decoder for the events written by `ERC1155::erc1155_emit`.
+//!
+//! Every event is stored as a `BTreeMap<String, String>` in its own URef. Versioned events
+//! carry an `event_version` field and are decoded losslessly back into `ERC1155Event`.
+//! Contracts installed in CES mode write records to the `__events` dictionary instead, which
+//! are decoded with [`decode_ces`].
+use casper_types::{
+    bytesrepr::{self, FromBytes},
+    CLValue, ContractPackageHash, Key, U256,
+};
+pub use erc1155_crate::ERC1155Event;
+use erc1155_crate::{
+    ces::EVENT_PREFIX,
+    data::{EVENT_LIST_SEPARATOR, EVENT_VERSION},
+};
+use std::{collections::BTreeMap, fmt};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    NotAnEvent,
+    MissingField(&'static str),
+    InvalidField { field: &'static str, value: String },
+    UnsupportedVersion(Option<String>),
+    UnknownEventType(String),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::NotAnEvent => write!(f, "stored value is not an event"),
+            DecodeError::MissingField(field) => write!(f, "missing field `{}`", field),
+            DecodeError::InvalidField { field, value } => {
+                write!(f, "invalid value `{}` for field `{}`", value, field)
+            }
+            DecodeError::UnsupportedVersion(Some(version)) => {
+                write!(f, "unsupported event version `{}`", version)
+            }
+            DecodeError::UnsupportedVersion(None) => {
+                write!(f, "unversioned events cannot be decoded losslessly")
+            }
+            DecodeError::UnknownEventType(event_type) => {
+                write!(f, "unknown event type `{}`", event_type)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// An event together with the package that emitted it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedEvent {
+    pub package_hash: ContractPackageHash,
+    pub event: ERC1155Event,
+}
+
+/// Decodes the value of an event URef as read from global state.
+pub fn decode_cl_value(value: CLValue) -> Result<DecodedEvent, DecodeError> {
+    let fields: BTreeMap<String, String> = value.into_t().map_err(|_| DecodeError::NotAnEvent)?;
+    decode(&fields)
+}
+
+/// Decodes the fields of a single emitted event.
+pub fn decode(fields: &BTreeMap<String, String>) -> Result<DecodedEvent, DecodeError> {
+    let fields = Fields(fields);
+    match fields.0.get("event_version") {
+        Some(version) if version == EVENT_VERSION => {}
+        version => return Err(DecodeError::UnsupportedVersion(version.cloned())),
+    }
+    let package_hash = fields.package_hash()?;
+    let event = match fields.get("event_type")? {
+        "TransferBatch" => ERC1155Event::TransferBatch {
+            operator: fields.key("operator")?,
+            from: fields.key("from")?,
+            to: fields.key("to")?,
+            ids: fields.u256_list("ids")?,
+            amounts: fields.u256_list("amounts")?,
+        },
+        "TransferSingle" => ERC1155Event::TransferSingle {
+            operator: fields.key("operator")?,
+            from: fields.key("from")?,
+            to: fields.key("to")?,
+            id: fields.u256("id")?,
+            amount: fields.u256("amount")?,
+        },
+        "approval for all" => ERC1155Event::ApprovalForAll {
+            owner: fields.key("owner")?,
+            operator: fields.key("operator")?,
+            approved: fields.parse("approved")?,
+            expires_at: fields.parse("expires_at")?,
+        },
+        "URI" => ERC1155Event::URI {
+            value: fields.get("value")?.to_string(),
+            id: fields.u256("id")?,
+        },
+        "BaseURI" => ERC1155Event::BaseURI {
+            value: fields.get("value")?.to_string(),
+        },
+        "Approval" => ERC1155Event::Approval {
+            owner: fields.key("owner")?,
+            operator: fields.key("operator")?,
+            id: fields.u256("id")?,
+            amount: fields.u256("amount")?,
+        },
+        "OwnershipTransferred" => ERC1155Event::OwnershipTransferred {
+            previous_owner: fields.key("previous_owner")?,
+            new_owner: fields.key("new_owner")?,
+        },
+        "RoleGranted" => ERC1155Event::RoleGranted {
+            role: fields.get("role")?.to_string(),
+            account: fields.key("account")?,
+            sender: fields.key("sender")?,
+        },
+        "RoleRevoked" => ERC1155Event::RoleRevoked {
+            role: fields.get("role")?.to_string(),
+            account: fields.key("account")?,
+            sender: fields.key("sender")?,
+        },
+        "RoleAdminChanged" => ERC1155Event::RoleAdminChanged {
+            role: fields.get("role")?.to_string(),
+            previous_admin_role: fields.get("previous_admin_role")?.to_string(),
+            new_admin_role: fields.get("new_admin_role")?.to_string(),
+        },
+        "Paused" => ERC1155Event::Paused {
+            account: fields.key("account")?,
+        },
+        "Unpaused" => ERC1155Event::Unpaused {
+            account: fields.key("account")?,
+        },
+        "OperatorFilterModeUpdated" => ERC1155Event::OperatorFilterModeUpdated {
+            mode: fields.parse("mode")?,
+        },
+        "OperatorFilterUpdated" => ERC1155Event::OperatorFilterUpdated {
+            operator: fields.key("operator")?,
+            listed: fields.parse("listed")?,
+        },
+        "TransfersLocked" => ERC1155Event::TransfersLocked {
+            id: fields.u256("id")?,
+        },
+        event_type => return Err(DecodeError::UnknownEventType(event_type.to_string())),
+    };
+    Ok(DecodedEvent {
+        package_hash,
+        event,
+    })
+}
+
+/// Decodes a record read from the CES `__events` dictionary.
+pub fn decode_ces(bytes: &[u8]) -> Result<ERC1155Event, DecodeError> {
+    let mut record = Record(bytes);
+    let name: String = record.read()?;
+    let event = match name.strip_prefix(EVENT_PREFIX) {
+        Some("TransferSingle") => ERC1155Event::TransferSingle {
+            operator: record.read()?,
+            from: record.read()?,
+            to: record.read()?,
+            id: record.read()?,
+            amount: record.read()?,
+        },
+        Some("TransferBatch") => ERC1155Event::TransferBatch {
+            operator: record.read()?,
+            from: record.read()?,
+            to: record.read()?,
+            ids: record.read()?,
+            amounts: record.read()?,
+        },
+        Some("ApprovalForAll") => ERC1155Event::ApprovalForAll {
+            owner: record.read()?,
+            operator: record.read()?,
+            approved: record.read()?,
+            expires_at: record.read()?,
+        },
+        Some("URI") => ERC1155Event::URI {
+            value: record.read()?,
+            id: record.read()?,
+        },
+        Some("BaseURI") => ERC1155Event::BaseURI {
+            value: record.read()?,
+        },
+        Some("Approval") => ERC1155Event::Approval {
+            owner: record.read()?,
+            operator: record.read()?,
+            id: record.read()?,
+            amount: record.read()?,
+        },
+        Some("OwnershipTransferred") => ERC1155Event::OwnershipTransferred {
+            previous_owner: record.read()?,
+            new_owner: record.read()?,
+        },
+        Some("RoleGranted") => ERC1155Event::RoleGranted {
+            role: record.read()?,
+            account: record.read()?,
+            sender: record.read()?,
+        },
+        Some("RoleRevoked") => ERC1155Event::RoleRevoked {
+            role: record.read()?,
+            account: record.read()?,
+            sender: record.read()?,
+        },
+        Some("RoleAdminChanged") => ERC1155Event::RoleAdminChanged {
+            role: record.read()?,
+            previous_admin_role: record.read()?,
+            new_admin_role: record.read()?,
+        },
+        Some("Paused") => ERC1155Event::Paused {
+            account: record.read()?,
+        },
+        Some("Unpaused") => ERC1155Event::Unpaused {
+            account: record.read()?,
+        },
+        Some("OperatorFilterModeUpdated") => ERC1155Event::OperatorFilterModeUpdated {
+            mode: record.read()?,
+        },
+        Some("OperatorFilterUpdated") => ERC1155Event::OperatorFilterUpdated {
+            operator: record.read()?,
+            listed: record.read()?,
+        },
+        Some("TransfersLocked") => ERC1155Event::TransfersLocked { id: record.read()? },
+        _ => return Err(DecodeError::UnknownEventType(name)),
+    };
+    if !record.0.is_empty() {
+        return Err(DecodeError::NotAnEvent);
+    }
+    Ok(event)
+}
+
+struct Record<'a>(&'a [u8]);
+
+impl<'a> Record<'a> {
+    fn read<T: FromBytes>(&mut self) -> Result<T, DecodeError> {
+        let (value, remainder) =
+            T::from_bytes(self.0).map_err(|_: bytesrepr::Error| DecodeError::NotAnEvent)?;
+        self.0 = remainder;
+        Ok(value)
+    }
+}
+
+struct Fields<'a>(&'a BTreeMap<String, String>);
+
+impl<'a> Fields<'a> {
+    fn get(&self, field: &'static str) -> Result<&'a str, DecodeError> {
+        self.0
+            .get(field)
+            .map(String::as_str)
+            .ok_or(DecodeError::MissingField(field))
+    }
+
+    fn invalid(field: &'static str, value: &str) -> DecodeError {
+        DecodeError::InvalidField {
+            field,
+            value: value.to_string(),
+        }
+    }
+
+    fn parse<T: std::str::FromStr>(&self, field: &'static str) -> Result<T, DecodeError> {
+        let value = self.get(field)?;
+        value.parse().map_err(|_| Self::invalid(field, value))
+    }
+
+    fn package_hash(&self) -> Result<ContractPackageHash, DecodeError> {
+        let value = self.get("contract_package_hash")?;
+        ContractPackageHash::from_formatted_str(value)
+            .map_err(|_| Self::invalid("contract_package_hash", value))
+    }
+
+    fn key(&self, field: &'static str) -> Result<Key, DecodeError> {
+        let value = self.get(field)?;
+        Key::from_formatted_str(value).map_err(|_| Self::invalid(field, value))
+    }
+
+    fn u256(&self, field: &'static str) -> Result<U256, DecodeError> {
+        let value = self.get(field)?;
+        U256::from_dec_str(value).map_err(|_| Self::invalid(field, value))
+    }
+
+    fn u256_list(&self, field: &'static str) -> Result<Vec<U256>, DecodeError> {
+        let value = self.get(field)?;
+        if value.is_empty() {
+            return Ok(Vec::new());
+        }
+        value
+            .split(EVENT_LIST_SEPARATOR)
+            .map(|elem| U256::from_dec_str(elem).map_err(|_| Self::invalid(field, value)))
+            .collect()
+    }
+}
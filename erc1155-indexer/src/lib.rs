@@ -0,0 +1,413 @@
+//! Rebuilds ERC1155 state from emitted events.
+//!
+//! Events are replayed in emission order into per-package balances, operator approvals,
+//! per-id allowances, supplies and transfer locks, which can then be checked against what
+//! the contract itself reports.
+use casper_types::{ContractPackageHash, Key, U256};
+use erc1155_crate::data::ZERO_ADDRESS;
+use erc1155_events::{decode, decode_ces, DecodeError, DecodedEvent, ERC1155Event};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    io::{self, BufRead},
+};
+
+#[derive(Debug)]
+pub enum IndexError {
+    Io(io::Error),
+    /// A recorded line is not a JSON object of string fields.
+    InvalidRecord {
+        line: usize,
+    },
+    Decode {
+        line: usize,
+        error: DecodeError,
+    },
+    /// A CES record, counted from zero, is not an event.
+    DecodeCes {
+        index: usize,
+        error: DecodeError,
+    },
+    /// A transfer or burn moves more than the indexed balance.
+    InsufficientBalance {
+        package_hash: ContractPackageHash,
+        account: Key,
+        id: U256,
+    },
+    /// A mint overflows or a burn underflows the indexed supply.
+    InvalidSupply {
+        package_hash: ContractPackageHash,
+        id: U256,
+    },
+    MismatchIdsAndAmounts,
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IndexError::Io(error) => write!(f, "{}", error),
+            IndexError::InvalidRecord { line } => write!(f, "line {}: invalid record", line),
+            IndexError::Decode { line, error } => write!(f, "line {}: {}", line, error),
+            IndexError::DecodeCes { index, error } => write!(f, "record {}: {}", index, error),
+            IndexError::InsufficientBalance {
+                package_hash,
+                account,
+                id,
+            } => write!(
+                f,
+                "{}: balance of {} for id {} would go negative",
+                package_hash, account, id
+            ),
+            IndexError::InvalidSupply { package_hash, id } => {
+                write!(f, "{}: supply of id {} out of range", package_hash, id)
+            }
+            IndexError::MismatchIdsAndAmounts => write!(f, "ids and amounts length mismatch"),
+        }
+    }
+}
+
+impl std::error::Error for IndexError {}
+
+impl From<io::Error> for IndexError {
+    fn from(error: io::Error) -> Self {
+        IndexError::Io(error)
+    }
+}
+
+/// State of a single token contract as rebuilt from its events.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TokenState {
+    pub balances: BTreeMap<(U256, Key), U256>,
+    pub operator_approvals: BTreeMap<(Key, Key), bool>,
+    /// Block time at which an operator approval lapses, zero meaning never.
+    pub approval_expiries: BTreeMap<(Key, Key), u64>,
+    pub allowances: BTreeMap<(Key, Key, U256), U256>,
+    pub supplies: BTreeMap<U256, U256>,
+    /// Ids whose transfers are locked.
+    pub locked: BTreeSet<U256>,
+}
+
+impl TokenState {
+    pub fn balance_of(&self, account: Key, id: U256) -> U256 {
+        self.balances
+            .get(&(id, account))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Whether `operator` was last approved by `account`, regardless of expiry.
+    pub fn is_approved_for_all(&self, account: Key, operator: Key) -> bool {
+        self.operator_approvals
+            .get(&(account, operator))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Same as the contract's `is_approved_for_all` when queried at `blocktime`.
+    pub fn is_approved_for_all_at(&self, account: Key, operator: Key, blocktime: u64) -> bool {
+        let expires_at = self.approval_expiry(account, operator);
+        self.is_approved_for_all(account, operator) && (expires_at == 0 || blocktime < expires_at)
+    }
+
+    pub fn approval_expiry(&self, account: Key, operator: Key) -> u64 {
+        self.approval_expiries
+            .get(&(account, operator))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn allowance(&self, owner: Key, operator: Key, id: U256) -> U256 {
+        self.allowances
+            .get(&(owner, operator, id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn total_supply(&self, id: U256) -> U256 {
+        self.supplies.get(&id).copied().unwrap_or_default()
+    }
+
+    pub fn is_transferable(&self, id: U256) -> bool {
+        !self.locked.contains(&id)
+    }
+}
+
+/// A balance the contract reports differently from the indexed one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub account: Key,
+    pub id: U256,
+    pub indexed: U256,
+    pub on_chain: U256,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Indexer {
+    tokens: BTreeMap<ContractPackageHash, TokenState>,
+}
+
+impl Indexer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn token(&self, package_hash: ContractPackageHash) -> Option<&TokenState> {
+        self.tokens.get(&package_hash)
+    }
+
+    pub fn balance_of(&self, package_hash: ContractPackageHash, account: Key, id: U256) -> U256 {
+        self.token(package_hash)
+            .map(|token| token.balance_of(account, id))
+            .unwrap_or_default()
+    }
+
+    pub fn balance_of_batch(
+        &self,
+        package_hash: ContractPackageHash,
+        accounts: &[Key],
+        ids: &[U256],
+    ) -> Vec<U256> {
+        accounts
+            .iter()
+            .zip(ids)
+            .map(|(account, id)| self.balance_of(package_hash, *account, *id))
+            .collect()
+    }
+
+    pub fn is_approved_for_all(
+        &self,
+        package_hash: ContractPackageHash,
+        account: Key,
+        operator: Key,
+    ) -> bool {
+        self.token(package_hash)
+            .map(|token| token.is_approved_for_all(account, operator))
+            .unwrap_or_default()
+    }
+
+    pub fn is_approved_for_all_at(
+        &self,
+        package_hash: ContractPackageHash,
+        account: Key,
+        operator: Key,
+        blocktime: u64,
+    ) -> bool {
+        self.token(package_hash)
+            .map(|token| token.is_approved_for_all_at(account, operator, blocktime))
+            .unwrap_or_default()
+    }
+
+    pub fn allowance(
+        &self,
+        package_hash: ContractPackageHash,
+        owner: Key,
+        operator: Key,
+        id: U256,
+    ) -> U256 {
+        self.token(package_hash)
+            .map(|token| token.allowance(owner, operator, id))
+            .unwrap_or_default()
+    }
+
+    pub fn total_supply(&self, package_hash: ContractPackageHash, id: U256) -> U256 {
+        self.token(package_hash)
+            .map(|token| token.total_supply(id))
+            .unwrap_or_default()
+    }
+
+    pub fn is_transferable(&self, package_hash: ContractPackageHash, id: U256) -> bool {
+        self.token(package_hash)
+            .map_or(true, |token| token.is_transferable(id))
+    }
+
+    /// Replays decoded events in emission order.
+    pub fn replay<I: IntoIterator<Item = DecodedEvent>>(
+        &mut self,
+        events: I,
+    ) -> Result<(), IndexError> {
+        for decoded in events {
+            self.apply(decoded.package_hash, &decoded.event)?;
+        }
+        Ok(())
+    }
+
+    /// Replays the records of a contract installed in CES mode, in the order they were written
+    /// to its `__events` dictionary. Records do not name the emitting package, so it is passed
+    /// in.
+    pub fn replay_ces<I>(
+        &mut self,
+        package_hash: ContractPackageHash,
+        records: I,
+    ) -> Result<(), IndexError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        for (index, record) in records.into_iter().enumerate() {
+            let event = decode_ces(record.as_ref())
+                .map_err(|error| IndexError::DecodeCes { index, error })?;
+            self.apply(package_hash, &event)?;
+        }
+        Ok(())
+    }
+
+    /// Replays a recording with one event per line, each a JSON object holding the fields
+    /// of an emitted event. Blank lines are skipped.
+    pub fn replay_recording<R: BufRead>(&mut self, recording: R) -> Result<(), IndexError> {
+        for (index, line) in recording.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let line_number = index + 1;
+            let fields: BTreeMap<String, String> = serde_json::from_str(&line)
+                .map_err(|_| IndexError::InvalidRecord { line: line_number })?;
+            let decoded = decode(&fields).map_err(|error| IndexError::Decode {
+                line: line_number,
+                error,
+            })?;
+            self.apply(decoded.package_hash, &decoded.event)?;
+        }
+        Ok(())
+    }
+
+    /// Applies a single event emitted by `package_hash`. Events that do not touch balances,
+    /// approvals, allowances, supplies or transfer locks are ignored. An error means the stream is
+    /// inconsistent with the contract, so the indexed state should no longer be trusted.
+    pub fn apply(
+        &mut self,
+        package_hash: ContractPackageHash,
+        event: &ERC1155Event,
+    ) -> Result<(), IndexError> {
+        let token = self.tokens.entry(package_hash).or_default();
+        match event {
+            ERC1155Event::TransferSingle {
+                from,
+                to,
+                id,
+                amount,
+                ..
+            } => Self::transfer(token, package_hash, *from, *to, *id, *amount)?,
+            ERC1155Event::TransferBatch {
+                from,
+                to,
+                ids,
+                amounts,
+                ..
+            } => {
+                if ids.len() != amounts.len() {
+                    return Err(IndexError::MismatchIdsAndAmounts);
+                }
+                for (id, amount) in ids.iter().zip(amounts) {
+                    Self::transfer(token, package_hash, *from, *to, *id, *amount)?;
+                }
+            }
+            ERC1155Event::ApprovalForAll {
+                owner,
+                operator,
+                approved,
+                expires_at,
+            } => {
+                token
+                    .operator_approvals
+                    .insert((*owner, *operator), *approved);
+                token
+                    .approval_expiries
+                    .insert((*owner, *operator), *expires_at);
+            }
+            // Spending an allowance re-emits `Approval` with the remainder, so the latest
+            // event always holds the current value.
+            ERC1155Event::Approval {
+                owner,
+                operator,
+                id,
+                amount,
+            } => {
+                token.allowances.insert((*owner, *operator, *id), *amount);
+            }
+            ERC1155Event::TransfersLocked { id } => {
+                token.locked.insert(*id);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn transfer(
+        token: &mut TokenState,
+        package_hash: ContractPackageHash,
+        from: Key,
+        to: Key,
+        id: U256,
+        amount: U256,
+    ) -> Result<(), IndexError> {
+        if from == ZERO_ADDRESS() {
+            let supply = token
+                .total_supply(id)
+                .checked_add(amount)
+                .ok_or(IndexError::InvalidSupply { package_hash, id })?;
+            token.supplies.insert(id, supply);
+        } else {
+            let balance = token.balance_of(from, id).checked_sub(amount).ok_or(
+                IndexError::InsufficientBalance {
+                    package_hash,
+                    account: from,
+                    id,
+                },
+            )?;
+            token.balances.insert((id, from), balance);
+        }
+        if to == ZERO_ADDRESS() {
+            let supply = token
+                .total_supply(id)
+                .checked_sub(amount)
+                .ok_or(IndexError::InvalidSupply { package_hash, id })?;
+            token.supplies.insert(id, supply);
+        } else {
+            let balance = token.balance_of(to, id) + amount;
+            token.balances.insert((id, to), balance);
+        }
+        Ok(())
+    }
+
+    /// Compares the result of the contract's `balance_of_batch(accounts, ids)` with the
+    /// indexed balances and returns every entry that differs.
+    pub fn check_balance_of_batch(
+        &self,
+        package_hash: ContractPackageHash,
+        accounts: &[Key],
+        ids: &[U256],
+        on_chain: &[U256],
+    ) -> Vec<Mismatch> {
+        accounts
+            .iter()
+            .zip(ids)
+            .zip(on_chain)
+            .filter_map(|((account, id), on_chain)| {
+                let indexed = self.balance_of(package_hash, *account, *id);
+                if indexed == *on_chain {
+                    None
+                } else {
+                    Some(Mismatch {
+                        account: *account,
+                        id: *id,
+                        indexed,
+                        on_chain: *on_chain,
+                    })
+                }
+            })
+            .collect()
+    }
+
+    /// Same as [`Indexer::check_balance_of_batch`] for a single `balance_of` result.
+    pub fn check_balance_of(
+        &self,
+        package_hash: ContractPackageHash,
+        account: Key,
+        id: U256,
+        on_chain: U256,
+    ) -> Option<Mismatch> {
+        self.check_balance_of_batch(package_hash, &[account], &[id], &[on_chain])
+            .pop()
+    }
+}
@@ -73,15 +73,178 @@ pub extern "C" fn call() {
             );
             store(IS_APPROVED_FOR_ALL, ret);
         }
+        ALLOWANCE => {
+            let owner: Key = runtime::get_named_arg("owner");
+            let operator: Key = runtime::get_named_arg("operator");
+            let id: U256 = runtime::get_named_arg("id");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                ALLOWANCE,
+                runtime_args! {
+                    "owner" => owner,
+                    "operator" => operator,
+                    "id" => id
+                },
+            );
+            store(ALLOWANCE, ret);
+        }
         URI => {
+            let id: U256 = runtime::get_named_arg("id");
             let ret: String = runtime::call_versioned_contract(
                 package_hash.into_hash().unwrap_or_revert().into(),
                 None,
                 URI,
-                runtime_args! {},
+                runtime_args! {
+                    "id" => id
+                },
             );
             store(URI, ret);
         }
+        NAME | SYMBOL | CONTRACT_URI => {
+            let ret: String = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                entrypoint.as_str(),
+                runtime_args! {},
+            );
+            store(entrypoint.as_str(), ret);
+        }
+        TOTAL_SUPPLY => {
+            let id: U256 = runtime::get_named_arg("id");
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                TOTAL_SUPPLY,
+                runtime_args! {
+                    "id" => id
+                },
+            );
+            store(TOTAL_SUPPLY, ret);
+        }
+        TOTAL_SUPPLY_ALL => {
+            let ret: U256 = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                TOTAL_SUPPLY_ALL,
+                runtime_args! {},
+            );
+            store(TOTAL_SUPPLY_ALL, ret);
+        }
+        EXISTS => {
+            let id: U256 = runtime::get_named_arg("id");
+            let ret: bool = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                EXISTS,
+                runtime_args! {
+                    "id" => id
+                },
+            );
+            store(EXISTS, ret);
+        }
+        OWNER => {
+            let ret: Key = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                OWNER,
+                runtime_args! {},
+            );
+            store(OWNER, ret);
+        }
+        HAS_ROLE => {
+            let role: String = runtime::get_named_arg("role");
+            let account: Key = runtime::get_named_arg("account");
+            let ret: bool = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                HAS_ROLE,
+                runtime_args! {
+                    "role" => role,
+                    "account" => account
+                },
+            );
+            store(HAS_ROLE, ret);
+        }
+        PAUSED => {
+            let ret: bool = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                PAUSED,
+                runtime_args! {},
+            );
+            store(PAUSED, ret);
+        }
+        TOKENS_OF_OWNER => {
+            let owner: Key = runtime::get_named_arg("owner");
+            let page: U256 = runtime::get_named_arg("page");
+            let size: U256 = runtime::get_named_arg("size");
+            let ret: Vec<U256> = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                TOKENS_OF_OWNER,
+                runtime_args! {
+                    "owner" => owner,
+                    "page" => page,
+                    "size" => size
+                },
+            );
+            store(TOKENS_OF_OWNER, ret);
+        }
+        HOLDERS_OF => {
+            let id: U256 = runtime::get_named_arg("id");
+            let page: U256 = runtime::get_named_arg("page");
+            let size: U256 = runtime::get_named_arg("size");
+            let ret: Vec<Key> = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                HOLDERS_OF,
+                runtime_args! {
+                    "id" => id,
+                    "page" => page,
+                    "size" => size
+                },
+            );
+            store(HOLDERS_OF, ret);
+        }
+        OWNER_OF => {
+            let id: U256 = runtime::get_named_arg("id");
+            let ret: Key = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                OWNER_OF,
+                runtime_args! {
+                    "id" => id
+                },
+            );
+            store(OWNER_OF, ret);
+        }
+        ROYALTY_INFO => {
+            let id: U256 = runtime::get_named_arg("id");
+            let sale_price: U256 = runtime::get_named_arg("sale_price");
+            let ret: (Key, U256) = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                ROYALTY_INFO,
+                runtime_args! {
+                    "id" => id,
+                    "sale_price" => sale_price
+                },
+            );
+            store(ROYALTY_INFO, ret);
+        }
+        SUPPORTS_INTERFACE => {
+            let interface_id: u32 = runtime::get_named_arg("interface_id");
+            let ret: bool = runtime::call_versioned_contract(
+                package_hash.into_hash().unwrap_or_revert().into(),
+                None,
+                SUPPORTS_INTERFACE,
+                runtime_args! {
+                    "interface_id" => interface_id
+                },
+            );
+            store(SUPPORTS_INTERFACE, ret);
+        }
         _ => runtime::revert(ApiError::UnexpectedKeyVariant),
     };
 }
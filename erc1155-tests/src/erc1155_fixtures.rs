@@ -0,0 +1,64 @@
+//! Setup shared by the native unit tests, which run the ERC1155 traits against the in-memory
+//! runtime from `erc1155_crate::testing`.
+use casper_types::{account::AccountHash, ContractHash, ContractPackageHash, Key};
+use erc1155_crate::{
+    ces::EventsMode,
+    testing::{self, InMemoryContractStorage},
+    ERC1155Event, ERC1155,
+};
+use erc1155_events::decode_cl_value;
+
+// Declares a token struct backed by the in-memory storage. The extensions and hooks under
+// test are implemented on it by each test file.
+macro_rules! test_token {
+    ($vis:vis $name:ident) => {
+        #[derive(Default)]
+        $vis struct $name(erc1155_crate::testing::InMemoryContractStorage);
+
+        impl
+            casperlabs_contract_utils::ContractContext<
+                erc1155_crate::testing::InMemoryContractStorage,
+            > for $name
+        {
+            fn storage(&self) -> &erc1155_crate::testing::InMemoryContractStorage {
+                &self.0
+            }
+        }
+    };
+}
+pub(crate) use test_token;
+
+pub(crate) fn account(seed: u8) -> Key {
+    Key::Account(AccountHash::new([seed; 32]))
+}
+
+// Resets the environment and initializes the base token with account 1 as the caller. Each
+// test file initializes the extensions it needs on top.
+pub(crate) fn deploy<T: Default + ERC1155<InMemoryContractStorage>>() -> T {
+    deploy_with(testing::contract(), EventsMode::Native)
+}
+
+pub(crate) fn deploy_with<T: Default + ERC1155<InMemoryContractStorage>>(
+    package_hash: ContractPackageHash,
+    events_mode: EventsMode,
+) -> T {
+    testing::reset();
+    testing::set_caller(account(1));
+    let token = T::default();
+    ERC1155::init(
+        &token,
+        "Token".into(),
+        "TKN".into(),
+        "https://example.com/{id}.json".into(),
+        Key::from(ContractHash::new([0xee; 32])),
+        package_hash,
+        events_mode,
+    );
+    token
+}
+
+pub(crate) fn last_event() -> ERC1155Event {
+    let decoded = decode_cl_value(testing::uref_values().pop().unwrap()).unwrap();
+    assert_eq!(decoded.package_hash, testing::contract());
+    decoded.event
+}
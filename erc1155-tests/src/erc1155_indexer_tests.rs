@@ -0,0 +1,199 @@
+use crate::{
+    erc1155_fixtures::account,
+    erc1155_unit_tests::{deploy, deploy_with, Token},
+};
+use casper_types::{bytesrepr::Bytes, Key, U256};
+use erc1155_crate::{
+    ces::{self, EventsMode},
+    testing, ERC1155Burnable, ERC1155Supply, ERC1155,
+};
+use erc1155_events::{decode_cl_value, DecodedEvent, ERC1155Event};
+use erc1155_indexer::{IndexError, Indexer, Mismatch};
+use std::collections::BTreeMap;
+
+fn events() -> Vec<DecodedEvent> {
+    testing::uref_values()
+        .into_iter()
+        .map(|value| decode_cl_value(value).unwrap())
+        .collect()
+}
+
+fn activity() -> (Token, Key) {
+    activity_in(EventsMode::Native)
+}
+
+fn activity_in(events_mode: EventsMode) -> (Token, Key) {
+    let (mut token, owner) = deploy_with(events_mode);
+    token._mint_batch(
+        owner,
+        vec![1.into(), 2.into()],
+        vec![100.into(), 50.into()],
+        Bytes::new(),
+    );
+    token.set_approval_for_all(account(3), true);
+    testing::set_caller(account(3));
+    token.safe_transfer_from(owner, account(2), 1.into(), 30.into(), "".into());
+    testing::set_caller(owner);
+    token.burn(owner, 2.into(), 20.into());
+    (token, owner)
+}
+
+fn assert_consistent(indexer: &Indexer, token: &Token, owner: Key) {
+    let package_hash = testing::contract();
+    let accounts = vec![owner, owner, account(2), account(2)];
+    let ids: Vec<U256> = vec![1.into(), 2.into(), 1.into(), 2.into()];
+    let on_chain = token.balance_of_batch(accounts.clone(), ids.clone());
+    assert!(indexer
+        .check_balance_of_batch(package_hash, &accounts, &ids, &on_chain)
+        .is_empty());
+    for id in ids {
+        assert_eq!(
+            indexer.total_supply(package_hash, id),
+            token.total_supply(id)
+        );
+    }
+    assert!(indexer.is_approved_for_all(package_hash, owner, account(3)));
+}
+
+#[test]
+fn test_replay_matches_contract_state() {
+    let (token, owner) = activity();
+    let mut indexer = Indexer::new();
+    indexer.replay(events()).unwrap();
+    assert_consistent(&indexer, &token, owner);
+}
+
+#[test]
+fn test_replay_ces() {
+    let (token, owner) = activity_in(EventsMode::CES);
+    let length: u32 = testing::get_key(ces::EVENTS_LENGTH).unwrap();
+    let events = testing::Dict::instance(ces::EVENTS);
+    let records: Vec<Bytes> = (0..length)
+        .map(|index| events.get(&index.to_string()).unwrap())
+        .collect();
+    let mut indexer = Indexer::new();
+    indexer.replay_ces(testing::contract(), records).unwrap();
+    assert_consistent(&indexer, &token, owner);
+}
+
+#[test]
+fn test_replay_ces_invalid_record() {
+    let mut indexer = Indexer::new();
+    let error = indexer
+        .replay_ces(testing::contract(), vec![vec![0xff]])
+        .unwrap_err();
+    assert!(matches!(error, IndexError::DecodeCes { index: 0, .. }));
+}
+
+#[test]
+fn test_replay_recording() {
+    let (token, owner) = activity();
+    let recording: String = testing::uref_values()
+        .into_iter()
+        .map(|value| {
+            let fields: BTreeMap<String, String> = value.into_t().unwrap();
+            serde_json::to_string(&fields).unwrap() + "\n"
+        })
+        .collect();
+    let mut indexer = Indexer::new();
+    indexer.replay_recording(recording.as_bytes()).unwrap();
+    assert_consistent(&indexer, &token, owner);
+}
+
+#[test]
+fn test_replay_recording_invalid_line() {
+    let mut indexer = Indexer::new();
+    let result = indexer.replay_recording("\n[1, 2]\n".as_bytes());
+    assert!(matches!(result, Err(IndexError::InvalidRecord { line: 2 })));
+}
+
+#[test]
+fn test_check_balance_of_detects_missing_event() {
+    let (token, owner) = activity();
+    let mut indexer = Indexer::new();
+    // Drop the burn so the indexed balance falls behind the contract.
+    let mut events = events();
+    events.pop();
+    indexer.replay(events).unwrap();
+    let package_hash = testing::contract();
+    assert_eq!(
+        indexer.check_balance_of(
+            package_hash,
+            owner,
+            2.into(),
+            token.balance_of(owner, 2.into())
+        ),
+        Some(Mismatch {
+            account: owner,
+            id: 2.into(),
+            indexed: 50.into(),
+            on_chain: 30.into(),
+        })
+    );
+}
+
+#[test]
+fn test_replay_transfer_without_balance() {
+    let mut indexer = Indexer::new();
+    let package_hash = testing::contract();
+    let result = indexer.apply(
+        package_hash,
+        &ERC1155Event::TransferSingle {
+            operator: account(1),
+            from: account(1),
+            to: account(2),
+            id: 1.into(),
+            amount: 1.into(),
+        },
+    );
+    assert!(matches!(
+        result,
+        Err(IndexError::InsufficientBalance { .. })
+    ));
+}
+
+#[test]
+fn test_replay_tracks_allowances() {
+    let (mut token, owner) = deploy();
+    token._mint(owner, 1.into(), 10.into(), Bytes::new());
+    token.approve(account(3), 1.into(), 6.into());
+    testing::set_caller(account(3));
+    token.safe_transfer_from(owner, account(2), 1.into(), 4.into(), "".into());
+    let mut indexer = Indexer::new();
+    indexer.replay(events()).unwrap();
+    assert_eq!(
+        indexer.allowance(testing::contract(), owner, account(3), 1.into()),
+        token.allowance(owner, account(3), 1.into())
+    );
+    assert_eq!(
+        indexer.balance_of(testing::contract(), account(2), 1.into()),
+        4.into()
+    );
+}
+
+#[test]
+fn test_replay_tracks_approval_expiry() {
+    let (mut token, owner) = deploy();
+    testing::set_blocktime(100);
+    token.set_approval_for_all_until(account(3), 200);
+    let mut indexer = Indexer::new();
+    indexer.replay(events()).unwrap();
+    let package_hash = testing::contract();
+    assert!(indexer.is_approved_for_all_at(package_hash, owner, account(3), 199));
+    assert!(!indexer.is_approved_for_all_at(package_hash, owner, account(3), 200));
+}
+
+#[test]
+fn test_replay_tracks_transfer_locks() {
+    let mut indexer = Indexer::new();
+    let package_hash = testing::contract();
+    assert!(indexer.is_transferable(package_hash, 1.into()));
+    indexer
+        .apply(
+            package_hash,
+            &ERC1155Event::TransfersLocked { id: 1.into() },
+        )
+        .unwrap();
+    assert!(!indexer.is_transferable(package_hash, 1.into()));
+    assert!(indexer.is_transferable(package_hash, 2.into()));
+}
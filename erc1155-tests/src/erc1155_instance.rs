@@ -1,9 +1,8 @@
 use casper_types::{
-    account::AccountHash,
-    bytesrepr::{Bytes, FromBytes},
-    runtime_args, CLTyped, Key, RuntimeArgs, U256,
+    account::AccountHash, bytesrepr::FromBytes, runtime_args, CLTyped, Key, RuntimeArgs, U256,
 };
 use casperlabs_test_env::{TestContract, TestEnv};
+use erc1155_crate::ces::EventsMode;
 
 pub struct ERC1155Instance(TestContract);
 
@@ -15,7 +14,10 @@ impl ERC1155Instance {
         env: &TestEnv,
         contract_name: &str,
         sender: AccountHash,
+        name: String,
+        symbol: String,
         uri: String,
+        contract_uri: String,
     ) -> TestContract {
         TestContract::new(
             env,
@@ -23,7 +25,11 @@ impl ERC1155Instance {
             contract_name,
             sender,
             runtime_args! {
-                "uri" => uri
+                "name" => name,
+                "symbol" => symbol,
+                "uri" => uri,
+                "contract_uri" => contract_uri,
+                "events_mode" => EventsMode::Native as u8
             },
             0,
         )
@@ -40,8 +46,46 @@ impl ERC1155Instance {
             0,
         );
     }
-    pub fn uri(&self, sender: AccountHash) {
-        self.0.call_contract(sender, "uri", runtime_args! {}, 0);
+    pub fn uri(&self, sender: AccountHash, id: U256) {
+        self.0.call_contract(
+            sender,
+            "uri",
+            runtime_args! {
+                "id" => id
+            },
+            0,
+        );
+    }
+    pub fn set_uri(&self, sender: AccountHash, uri: String) {
+        self.0.call_contract(
+            sender,
+            "set_uri",
+            runtime_args! {
+                "uri" => uri
+            },
+            0,
+        );
+    }
+    pub fn set_contract_uri(&self, sender: AccountHash, contract_uri: String) {
+        self.0.call_contract(
+            sender,
+            "set_contract_uri",
+            runtime_args! {
+                "contract_uri" => contract_uri
+            },
+            0,
+        );
+    }
+    pub fn set_token_uri(&self, sender: AccountHash, id: U256, token_uri: String) {
+        self.0.call_contract(
+            sender,
+            "set_token_uri",
+            runtime_args! {
+                "id" => id,
+                "token_uri" => token_uri
+            },
+            0,
+        );
     }
 
     pub fn is_approved_for_all(&self, sender: AccountHash, account: Key, operator: Key) {
@@ -69,6 +113,53 @@ impl ERC1155Instance {
         );
     }
 
+    pub fn set_operator_filter_mode(&self, sender: AccountHash, mode: u8) {
+        self.0.call_contract(
+            sender,
+            "set_operator_filter_mode",
+            runtime_args! {
+                "mode" => mode
+            },
+            0,
+        );
+    }
+
+    pub fn set_operator_listed(&self, sender: AccountHash, operator: Key, listed: bool) {
+        self.0.call_contract(
+            sender,
+            "set_operator_listed",
+            runtime_args! {
+                "operator" => operator,
+                "listed" => listed
+            },
+            0,
+        );
+    }
+
+    pub fn lock_transfers(&self, sender: AccountHash, id: U256) {
+        self.0.call_contract(
+            sender,
+            "lock_transfers",
+            runtime_args! {
+                "id" => id
+            },
+            0,
+        );
+    }
+
+    pub fn approve(&self, sender: AccountHash, operator: Key, id: U256, amount: U256) {
+        self.0.call_contract(
+            sender,
+            "approve",
+            runtime_args! {
+                "operator" => operator,
+                "id" => id,
+                "amount" => amount
+            },
+            0,
+        );
+    }
+
     pub fn safe_transfer_from(
         &self,
         sender: AccountHash,
@@ -76,7 +167,7 @@ impl ERC1155Instance {
         to: Key,
         id: U256,
         amount: U256,
-        _data: Bytes,
+        data: String,
     ) {
         self.0.call_contract(
             sender,
@@ -87,7 +178,7 @@ impl ERC1155Instance {
                "to"=>  to,
                "id"=>id,
                "amount"=>amount,
-               "_data"=>_data
+               "data"=>data
             },
             0,
         );
@@ -99,22 +190,189 @@ impl ERC1155Instance {
         to: Key,
         ids: Vec<U256>,
         amounts: Vec<U256>,
-        _data: Bytes,
+        data: String,
     ) {
         self.0.call_contract(
             sender,
-            "safe_transfer_from",
+            "safe_batch_transfer_from",
             runtime_args! {
 
             "from"=>from,
                "to"=>  to,
                "ids"=>ids,
                "amounts"=>amounts,
-               "_data"=>_data
+               "data"=>data
+            },
+            0,
+        );
+    }
+
+    pub fn mint(&self, sender: AccountHash, to: Key, id: U256, amount: U256, data: String) {
+        self.0.call_contract(
+            sender,
+            "mint",
+            runtime_args! {
+                "to" => to,
+                "id" => id,
+                "amount" => amount,
+                "data" => data,
+            },
+            0,
+        );
+    }
+    pub fn mint_non_fungible(&self, sender: AccountHash, type_id: U256, recipients: Vec<Key>) {
+        self.0.call_contract(
+            sender,
+            "mint_non_fungible",
+            runtime_args! {
+                "type_id" => type_id,
+                "recipients" => recipients
             },
             0,
         );
     }
+    pub fn mint_batch(
+        &self,
+        sender: AccountHash,
+        to: Key,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        data: String,
+    ) {
+        self.0.call_contract(
+            sender,
+            "mint_batch",
+            runtime_args! {
+                "to" => to,
+                "ids" => ids,
+                "amounts" => amounts,
+                "data" => data,
+            },
+            0,
+        );
+    }
+    pub fn burn(&self, sender: AccountHash, from: Key, id: U256, amount: U256) {
+        self.0.call_contract(
+            sender,
+            "burn",
+            runtime_args! {
+                "from" => from,
+                "id" => id,
+                "amount" => amount
+            },
+            0,
+        );
+    }
+    pub fn set_max_supply(&self, sender: AccountHash, id: U256, max_supply: U256, immutable: bool) {
+        self.0.call_contract(
+            sender,
+            "set_max_supply",
+            runtime_args! {
+                "id" => id,
+                "max_supply" => max_supply,
+                "immutable" => immutable
+            },
+            0,
+        );
+    }
+    pub fn set_default_royalty(&self, sender: AccountHash, receiver: Key, fraction: U256) {
+        self.0.call_contract(
+            sender,
+            "set_default_royalty",
+            runtime_args! {
+                "receiver" => receiver,
+                "fraction" => fraction
+            },
+            0,
+        );
+    }
+    pub fn set_token_royalty(&self, sender: AccountHash, id: U256, receiver: Key, fraction: U256) {
+        self.0.call_contract(
+            sender,
+            "set_token_royalty",
+            runtime_args! {
+                "id" => id,
+                "receiver" => receiver,
+                "fraction" => fraction
+            },
+            0,
+        );
+    }
+    pub fn create_token_type(
+        &self,
+        sender: AccountHash,
+        kind: u8,
+        metadata_uri: String,
+        max_supply: U256,
+        transferable: bool,
+    ) {
+        self.0.call_contract(
+            sender,
+            "create_token_type",
+            runtime_args! {
+                "kind" => kind,
+                "metadata_uri" => metadata_uri,
+                "max_supply" => max_supply,
+                "transferable" => transferable
+            },
+            0,
+        );
+    }
+    pub fn transfer_ownership(&self, sender: AccountHash, new_owner: Key) {
+        self.0.call_contract(
+            sender,
+            "transfer_ownership",
+            runtime_args! {
+                "new_owner" => new_owner
+            },
+            0,
+        );
+    }
+    pub fn renounce_ownership(&self, sender: AccountHash) {
+        self.0
+            .call_contract(sender, "renounce_ownership", runtime_args! {}, 0);
+    }
+
+    pub fn grant_role(&self, sender: AccountHash, role: &str, account: Key) {
+        self.0.call_contract(
+            sender,
+            "grant_role",
+            runtime_args! {
+                "role" => role.to_string(),
+                "account" => account
+            },
+            0,
+        );
+    }
+    pub fn revoke_role(&self, sender: AccountHash, role: &str, account: Key) {
+        self.0.call_contract(
+            sender,
+            "revoke_role",
+            runtime_args! {
+                "role" => role.to_string(),
+                "account" => account
+            },
+            0,
+        );
+    }
+    pub fn renounce_role(&self, sender: AccountHash, role: &str, account: Key) {
+        self.0.call_contract(
+            sender,
+            "renounce_role",
+            runtime_args! {
+                "role" => role.to_string(),
+                "account" => account
+            },
+            0,
+        );
+    }
+
+    pub fn pause(&self, sender: AccountHash) {
+        self.0.call_contract(sender, "pause", runtime_args! {}, 0);
+    }
+    pub fn unpause(&self, sender: AccountHash) {
+        self.0.call_contract(sender, "unpause", runtime_args! {}, 0);
+    }
 
     // Result methods
     pub fn result<T: CLTyped + FromBytes>(&self) -> T {
@@ -0,0 +1,129 @@
+use crate::erc1155_fixtures::{self, account, test_token};
+use casper_types::{bytesrepr::Bytes, ApiError, Key, U256};
+use erc1155_crate::{
+    data::ZERO_ADDRESS,
+    testing::{self, InMemoryContractStorage},
+    ERC1155Burnable, ERC1155MixedFungible, ERC1155Supply, Error, ERC1155, TYPE_NF_BIT,
+};
+
+test_token!(MixedToken);
+
+impl ERC1155<InMemoryContractStorage> for MixedToken {
+    fn _before_token_transfer(
+        &mut self,
+        _operator: Key,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        _data: Bytes,
+    ) {
+        ERC1155MixedFungible::_update_non_fungible_owners(self, from, to, &ids, &amounts);
+        ERC1155Supply::_update_supply(self, from, to, &ids, &amounts);
+    }
+}
+impl ERC1155Supply<InMemoryContractStorage> for MixedToken {}
+impl ERC1155Burnable<InMemoryContractStorage> for MixedToken {}
+impl ERC1155MixedFungible<InMemoryContractStorage> for MixedToken {}
+
+fn deploy() -> (MixedToken, Key) {
+    let token: MixedToken = erc1155_fixtures::deploy();
+    ERC1155Supply::init(&token);
+    ERC1155MixedFungible::init(&token);
+    (token, account(1))
+}
+
+fn non_fungible_type(base: u64) -> U256 {
+    TYPE_NF_BIT | (U256::from(base) << 128)
+}
+
+#[test]
+fn test_split_id_helpers() {
+    let (token, _) = deploy();
+    let type_id = non_fungible_type(7);
+    let item = type_id + 5;
+    assert!(token.is_non_fungible(type_id));
+    assert!(token.is_non_fungible(item));
+    assert!(!token.is_non_fungible(U256::from(7) << 128));
+    assert!(token.is_non_fungible_base_type(type_id));
+    assert!(!token.is_non_fungible_item(type_id));
+    assert!(token.is_non_fungible_item(item));
+    assert_eq!(token.get_base_type(item), type_id);
+    assert_eq!(token.get_index(item), 5.into());
+}
+
+#[test]
+fn test_mint_non_fungible_assigns_sequential_indices() {
+    let (mut token, owner) = deploy();
+    let type_id = non_fungible_type(1);
+    let ids = token._mint_non_fungible(type_id, vec![owner, account(2)]);
+    assert_eq!(ids, vec![type_id + 1, type_id + 2]);
+    let ids = token._mint_non_fungible(type_id, vec![account(3)]);
+    assert_eq!(ids, vec![type_id + 3]);
+    assert_eq!(token.owner_of(type_id + 1), owner);
+    assert_eq!(token.owner_of(type_id + 3), account(3));
+    assert_eq!(token.owner_of(type_id + 4), ZERO_ADDRESS());
+    assert_eq!(token.balance_of(account(2), type_id + 2), 1.into());
+    assert_eq!(token.total_supply(type_id + 2), 1.into());
+}
+
+#[test]
+fn test_mint_non_fungible_requires_base_type() {
+    let (mut token, owner) = deploy();
+    let result =
+        testing::catch_revert(|| token._mint_non_fungible(U256::one() << 128, vec![owner]));
+    assert_eq!(result, Err(ApiError::from(Error::NotNonFungibleType)));
+    let item = non_fungible_type(1) + 1;
+    let result = testing::catch_revert(|| token._mint_non_fungible(item, vec![owner]));
+    assert_eq!(result, Err(ApiError::from(Error::NotNonFungibleType)));
+}
+
+#[test]
+fn test_non_fungible_item_cannot_be_minted_directly() {
+    let (mut token, owner) = deploy();
+    let type_id = non_fungible_type(1);
+    let result = testing::catch_revert(|| token._mint(owner, type_id + 1, 1.into(), Bytes::new()));
+    assert_eq!(result, Err(ApiError::from(Error::InvalidNonFungibleIndex)));
+    token._mint_non_fungible(type_id, vec![owner]);
+    let result = testing::catch_revert(|| token._mint(owner, type_id + 1, 1.into(), Bytes::new()));
+    assert_eq!(result, Err(ApiError::from(Error::InvalidNonFungibleIndex)));
+    let result = testing::catch_revert(|| token._mint(owner, type_id, 1.into(), Bytes::new()));
+    assert_eq!(result, Err(ApiError::from(Error::InvalidNonFungibleIndex)));
+}
+
+#[test]
+fn test_transfer_and_burn_update_owner() {
+    let (mut token, owner) = deploy();
+    let type_id = non_fungible_type(1);
+    let id = token._mint_non_fungible(type_id, vec![owner])[0];
+    token.safe_transfer_from(owner, account(2), id, 1.into(), "".into());
+    assert_eq!(token.owner_of(id), account(2));
+    testing::set_caller(account(2));
+    token.burn(account(2), id, 1.into());
+    assert_eq!(token.owner_of(id), ZERO_ADDRESS());
+}
+
+#[test]
+fn test_burned_item_cannot_be_minted_again() {
+    let (mut token, owner) = deploy();
+    let type_id = non_fungible_type(1);
+    let id = token._mint_non_fungible(type_id, vec![owner])[0];
+    token.burn(owner, id, 1.into());
+    let result = testing::catch_revert(|| token._mint(owner, id, 1.into(), Bytes::new()));
+    assert_eq!(result, Err(ApiError::from(Error::InvalidNonFungibleIndex)));
+    assert_eq!(token.total_supply(id), U256::zero());
+    assert_eq!(
+        token._mint_non_fungible(type_id, vec![owner]),
+        vec![type_id + 2]
+    );
+}
+
+#[test]
+fn test_fungible_split_ids_are_stackable() {
+    let (mut token, owner) = deploy();
+    let resource = U256::from(2) << 128;
+    token._mint(owner, resource, 500.into(), Bytes::new());
+    token.safe_transfer_from(owner, account(2), resource, 200.into(), "".into());
+    assert_eq!(token.balance_of(owner, resource), 300.into());
+    assert_eq!(token.owner_of(resource), ZERO_ADDRESS());
+}
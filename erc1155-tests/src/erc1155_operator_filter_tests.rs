@@ -0,0 +1,142 @@
+use crate::erc1155_fixtures::{self, account, last_event, test_token};
+use casper_types::{bytesrepr::Bytes, ApiError, Key, U256};
+use core::convert::TryFrom;
+use erc1155_crate::{
+    ces,
+    testing::{self, InMemoryContractStorage},
+    ERC1155Event, ERC1155OperatorFilter, Error, OperatorFilterMode, Ownable, ERC1155,
+};
+use erc1155_events::decode_ces;
+
+test_token!(FilteredToken);
+
+impl ERC1155<InMemoryContractStorage> for FilteredToken {
+    fn _before_approval_for_all(&mut self, _owner: Key, operator: Key, approved: bool) {
+        self._filter_approval(operator, approved);
+    }
+    fn _before_token_transfer(
+        &mut self,
+        operator: Key,
+        from: Key,
+        _to: Key,
+        _ids: Vec<U256>,
+        _amounts: Vec<U256>,
+        _data: Bytes,
+    ) {
+        self._filter_transfer(operator, from);
+    }
+}
+impl Ownable<InMemoryContractStorage> for FilteredToken {}
+impl ERC1155OperatorFilter<InMemoryContractStorage> for FilteredToken {}
+
+// Account 1 owns the contract and holds 10 units of id 1.
+fn deploy() -> FilteredToken {
+    let mut token: FilteredToken = erc1155_fixtures::deploy();
+    Ownable::init(&mut token, account(1));
+    ERC1155OperatorFilter::init(&token);
+    token._mint(account(1), 1.into(), 10.into(), Bytes::new());
+    token
+}
+
+#[test]
+fn test_filter_disabled_by_default() {
+    let mut token = deploy();
+    assert_eq!(token.operator_filter_mode(), OperatorFilterMode::Disabled);
+    token.set_approval_for_all(account(3), true);
+    assert!(token.is_approved_for_all(account(1), account(3)));
+}
+
+#[test]
+fn test_deny_list() {
+    let mut token = deploy();
+    token.set_approval_for_all(account(3), true);
+    token.set_operator_filter_mode(OperatorFilterMode::DenyList);
+    token.set_operator_listed(account(3), true);
+    assert_eq!(
+        last_event(),
+        ERC1155Event::OperatorFilterUpdated {
+            operator: account(3),
+            listed: true,
+        }
+    );
+    assert!(!token.is_operator_allowed(account(3)));
+    assert!(token.is_operator_allowed(account(4)));
+    let result = testing::catch_revert(|| token.set_approval_for_all(account(3), true));
+    assert_eq!(result, Err(ApiError::from(Error::OperatorNotAllowed)));
+    testing::set_caller(account(3));
+    let result = testing::catch_revert(|| {
+        token.safe_transfer_from(account(1), account(3), 1.into(), 1.into(), "".into())
+    });
+    assert_eq!(result, Err(ApiError::from(Error::OperatorNotAllowed)));
+    testing::set_caller(account(1));
+    token.set_approval_for_all(account(3), false);
+    token.safe_transfer_from(account(1), account(3), 1.into(), 1.into(), "".into());
+    assert_eq!(token.balance_of(account(3), 1.into()), 1.into());
+}
+
+#[test]
+fn test_allow_list() {
+    let mut token = deploy();
+    token.set_operator_filter_mode(OperatorFilterMode::AllowList);
+    assert_eq!(
+        last_event(),
+        ERC1155Event::OperatorFilterModeUpdated {
+            mode: OperatorFilterMode::AllowList as u8,
+        }
+    );
+    let result = testing::catch_revert(|| token.set_approval_for_all(account(3), true));
+    assert_eq!(result, Err(ApiError::from(Error::OperatorNotAllowed)));
+    token.set_operator_listed(account(3), true);
+    token.set_approval_for_all(account(3), true);
+    testing::set_caller(account(3));
+    token.safe_transfer_from(account(1), account(2), 1.into(), 4.into(), "".into());
+    assert_eq!(token.balance_of(account(2), 1.into()), 4.into());
+}
+
+#[test]
+fn test_allow_list_filters_allowances() {
+    let mut token = deploy();
+    token.approve(account(3), 1.into(), 5.into());
+    token.set_operator_filter_mode(OperatorFilterMode::AllowList);
+    testing::set_caller(account(3));
+    let result = testing::catch_revert(|| {
+        token.safe_transfer_from(account(1), account(2), 1.into(), 1.into(), "".into())
+    });
+    assert_eq!(result, Err(ApiError::from(Error::OperatorNotAllowed)));
+}
+
+#[test]
+fn test_filter_administered_by_owner() {
+    let mut token = deploy();
+    testing::set_caller(account(2));
+    let result =
+        testing::catch_revert(|| token.set_operator_filter_mode(OperatorFilterMode::DenyList));
+    assert_eq!(result, Err(ApiError::from(Error::CallerIsNotOwner)));
+    let result = testing::catch_revert(|| token.set_operator_listed(account(3), true));
+    assert_eq!(result, Err(ApiError::from(Error::CallerIsNotOwner)));
+}
+
+#[test]
+fn test_invalid_filter_mode() {
+    assert_eq!(
+        OperatorFilterMode::try_from(3).map_err(ApiError::from),
+        Err(ApiError::from(Error::InvalidOperatorFilterMode))
+    );
+}
+
+#[test]
+fn test_filter_events_encode_as_ces() {
+    let events = [
+        ERC1155Event::OperatorFilterModeUpdated {
+            mode: OperatorFilterMode::DenyList as u8,
+        },
+        ERC1155Event::OperatorFilterUpdated {
+            operator: account(3),
+            listed: true,
+        },
+    ];
+    for event in events.iter() {
+        let bytes = ces::encode(event);
+        assert_eq!(&decode_ces(&bytes).unwrap(), event);
+    }
+}
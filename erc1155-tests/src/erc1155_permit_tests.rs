@@ -0,0 +1,338 @@
+use crate::erc1155_fixtures::{self, account, last_event, test_token};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::Bytes,
+    crypto::{PublicKey, SecretKey, Signature},
+    ApiError, ContractPackageHash, Key, U256,
+};
+use erc1155_crate::{
+    ces::EventsMode,
+    testing::{self, InMemoryContractStorage},
+    ERC1155Event, ERC1155Permit, Error, ERC1155,
+};
+
+test_token!(PermitToken);
+
+impl ERC1155<InMemoryContractStorage> for PermitToken {}
+impl ERC1155Permit<InMemoryContractStorage> for PermitToken {}
+
+// Signs digests the way a wallet holding the owner's key would.
+struct Signer {
+    secret_key: SecretKey,
+    public_key: PublicKey,
+}
+
+impl Signer {
+    fn ed25519(seed: u8) -> Signer {
+        Signer::new(SecretKey::ed25519_from_bytes([seed; 32]).unwrap())
+    }
+
+    fn secp256k1(seed: u8) -> Signer {
+        Signer::new(SecretKey::secp256k1_from_bytes([seed; 32]).unwrap())
+    }
+
+    fn new(secret_key: SecretKey) -> Signer {
+        let public_key = PublicKey::from(&secret_key);
+        Signer {
+            secret_key,
+            public_key,
+        }
+    }
+
+    fn public_key(&self) -> PublicKey {
+        self.public_key.clone()
+    }
+
+    fn account(&self) -> Key {
+        Key::Account(self.public_key.to_account_hash())
+    }
+
+    fn sign(&self, digest: [u8; 32]) -> Bytes {
+        Bytes::from(Vec::<u8>::from(sign(
+            digest,
+            &self.secret_key,
+            &self.public_key,
+        )))
+    }
+}
+
+// casper-types 1.5 has no `crypto::sign`, this follows the one added in later releases: Ed25519
+// signs the message itself and secp256k1 signs its SHA-256 digest.
+fn sign<T: AsRef<[u8]>>(message: T, secret_key: &SecretKey, public_key: &PublicKey) -> Signature {
+    match (secret_key, public_key) {
+        (SecretKey::Ed25519(secret_key), PublicKey::Ed25519(public_key)) => Signature::Ed25519(
+            ed25519_dalek::ExpandedSecretKey::from(secret_key).sign(message.as_ref(), public_key),
+        ),
+        (SecretKey::Secp256k1(secret_key), PublicKey::Secp256k1(_)) => Signature::Secp256k1(
+            k256::ecdsa::signature::Signer::sign(secret_key, message.as_ref()),
+        ),
+        _ => panic!("mismatched key pair"),
+    }
+}
+
+fn deploy() -> PermitToken {
+    deploy_at(testing::contract())
+}
+
+fn deploy_at(package_hash: ContractPackageHash) -> PermitToken {
+    let token: PermitToken = erc1155_fixtures::deploy_with(package_hash, EventsMode::Native);
+    ERC1155Permit::init(&token);
+    // Permits are relayed by an account that is neither owner nor operator.
+    testing::set_caller(account(9));
+    token
+}
+
+fn permit(token: &mut PermitToken, signer: &Signer, operator: Key, deadline: u64) {
+    let owner = signer.account();
+    let nonce = token.nonces(owner);
+    let digest = token.permit_for_all_digest(owner, operator, true, deadline, nonce);
+    token.permit_for_all(
+        owner,
+        operator,
+        true,
+        deadline,
+        nonce,
+        signer.sign(digest),
+        signer.public_key(),
+    );
+}
+
+#[test]
+fn test_permit_for_all() {
+    let operator = Key::Account(AccountHash::new([2; 32]));
+    for signer in [Signer::ed25519(1), Signer::secp256k1(1)] {
+        let mut token = deploy();
+        permit(&mut token, &signer, operator, 1000);
+        assert!(token.is_approved_for_all(signer.account(), operator));
+        assert_eq!(token.nonces(signer.account()), U256::one());
+    }
+}
+
+#[test]
+fn test_permit_for_all_replay() {
+    let mut token = deploy();
+    let signer = Signer::ed25519(1);
+    let (owner, operator) = (signer.account(), Key::Account(AccountHash::new([2; 32])));
+    let digest = token.permit_for_all_digest(owner, operator, true, 1000, U256::zero());
+    let signature = signer.sign(digest);
+    token.permit_for_all(
+        owner,
+        operator,
+        true,
+        1000,
+        U256::zero(),
+        signature.clone(),
+        signer.public_key(),
+    );
+    let result = testing::catch_revert(|| {
+        token.permit_for_all(
+            owner,
+            operator,
+            true,
+            1000,
+            U256::zero(),
+            signature,
+            signer.public_key(),
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::InvalidNonce)));
+}
+
+#[test]
+fn test_permit_for_all_expired() {
+    let mut token = deploy();
+    testing::set_blocktime(1001);
+    let result = testing::catch_revert(|| {
+        permit(
+            &mut token,
+            &Signer::ed25519(1),
+            Key::Account(AccountHash::new([2; 32])),
+            1000,
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::PermitExpired)));
+}
+
+#[test]
+fn test_permit_for_all_tampered() {
+    let mut token = deploy();
+    let signer = Signer::secp256k1(1);
+    let (owner, operator) = (signer.account(), Key::Account(AccountHash::new([2; 32])));
+    let digest = token.permit_for_all_digest(owner, operator, false, 1000, U256::zero());
+    let result = testing::catch_revert(|| {
+        token.permit_for_all(
+            owner,
+            operator,
+            true,
+            1000,
+            U256::zero(),
+            signer.sign(digest),
+            signer.public_key(),
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::InvalidSignature)));
+}
+
+#[test]
+fn test_permit_for_all_signer_is_not_owner() {
+    let mut token = deploy();
+    let (owner, signer) = (Signer::ed25519(1), Signer::ed25519(3));
+    let operator = Key::Account(AccountHash::new([2; 32]));
+    let digest = token.permit_for_all_digest(owner.account(), operator, true, 1000, U256::zero());
+    let result = testing::catch_revert(|| {
+        token.permit_for_all(
+            owner.account(),
+            operator,
+            true,
+            1000,
+            U256::zero(),
+            signer.sign(digest),
+            signer.public_key(),
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::InvalidSigner)));
+}
+
+#[test]
+fn test_permit_for_all_is_bound_to_package() {
+    let signer = Signer::ed25519(1);
+    let (owner, operator) = (signer.account(), Key::Account(AccountHash::new([2; 32])));
+    let token = deploy_at(ContractPackageHash::new([0xaa; 32]));
+    let signature =
+        signer.sign(token.permit_for_all_digest(owner, operator, true, 1000, U256::zero()));
+    let mut token = deploy();
+    let result = testing::catch_revert(|| {
+        token.permit_for_all(
+            owner,
+            operator,
+            true,
+            1000,
+            U256::zero(),
+            signature,
+            signer.public_key(),
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::InvalidSignature)));
+}
+
+#[test]
+fn test_transfer_with_signature() {
+    let mut token = deploy();
+    let signer = Signer::secp256k1(1);
+    let (from, to) = (signer.account(), Key::Account(AccountHash::new([2; 32])));
+    token._mint(from, 1.into(), 10.into(), Bytes::new());
+    let digest = token.transfer_digest(from, to, 1.into(), 4.into(), "".into(), 1000, U256::zero());
+    token.transfer_with_signature(
+        from,
+        to,
+        1.into(),
+        4.into(),
+        "".into(),
+        1000,
+        U256::zero(),
+        signer.sign(digest),
+        signer.public_key(),
+    );
+    assert_eq!(token.balance_of(from, 1.into()), 6.into());
+    assert_eq!(token.balance_of(to, 1.into()), 4.into());
+    assert_eq!(token.nonces(from), U256::one());
+    assert_eq!(
+        last_event(),
+        ERC1155Event::TransferSingle {
+            operator: from,
+            from,
+            to,
+            id: 1.into(),
+            amount: 4.into(),
+        }
+    );
+}
+
+#[test]
+fn test_batch_transfer_with_signature() {
+    let mut token = deploy();
+    let signer = Signer::ed25519(1);
+    let (from, to) = (signer.account(), Key::Account(AccountHash::new([2; 32])));
+    let (ids, amounts): (Vec<U256>, Vec<U256>) =
+        (vec![1.into(), 2.into()], vec![3.into(), 5.into()]);
+    token._mint_batch(from, ids.clone(), vec![10.into(), 10.into()], Bytes::new());
+    let digest = token.batch_transfer_digest(
+        from,
+        to,
+        ids.clone(),
+        amounts.clone(),
+        "".into(),
+        1000,
+        U256::zero(),
+    );
+    token.batch_transfer_with_signature(
+        from,
+        to,
+        ids.clone(),
+        amounts.clone(),
+        "".into(),
+        1000,
+        U256::zero(),
+        signer.sign(digest),
+        signer.public_key(),
+    );
+    assert_eq!(token.balance_of(to, 1.into()), 3.into());
+    assert_eq!(token.balance_of(to, 2.into()), 5.into());
+    assert_eq!(
+        last_event(),
+        ERC1155Event::TransferBatch {
+            operator: from,
+            from,
+            to,
+            ids,
+            amounts,
+        }
+    );
+}
+
+#[test]
+fn test_transfer_with_signature_shares_nonce_with_permit() {
+    let mut token = deploy();
+    let signer = Signer::ed25519(1);
+    let (from, to) = (signer.account(), Key::Account(AccountHash::new([2; 32])));
+    token._mint(from, 1.into(), 10.into(), Bytes::new());
+    let digest = token.transfer_digest(from, to, 1.into(), 4.into(), "".into(), 1000, U256::zero());
+    permit(&mut token, &signer, to, 1000);
+    let result = testing::catch_revert(|| {
+        token.transfer_with_signature(
+            from,
+            to,
+            1.into(),
+            4.into(),
+            "".into(),
+            1000,
+            U256::zero(),
+            signer.sign(digest),
+            signer.public_key(),
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::InvalidNonce)));
+}
+
+#[test]
+fn test_transfer_with_signature_tampered_amount() {
+    let mut token = deploy();
+    let signer = Signer::ed25519(1);
+    let (from, to) = (signer.account(), Key::Account(AccountHash::new([2; 32])));
+    token._mint(from, 1.into(), 10.into(), Bytes::new());
+    let digest = token.transfer_digest(from, to, 1.into(), 4.into(), "".into(), 1000, U256::zero());
+    let result = testing::catch_revert(|| {
+        token.transfer_with_signature(
+            from,
+            to,
+            1.into(),
+            10.into(),
+            "".into(),
+            1000,
+            U256::zero(),
+            signer.sign(digest),
+            signer.public_key(),
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::InvalidSignature)));
+}
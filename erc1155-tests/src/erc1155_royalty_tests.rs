@@ -0,0 +1,81 @@
+use crate::erc1155_fixtures::{self, account, test_token};
+use casper_types::{ApiError, U256};
+use erc1155_crate::{
+    data::ZERO_ADDRESS,
+    testing::{self, InMemoryContractStorage},
+    ERC1155Royalty, Error, ERC1155,
+};
+
+test_token!(RoyaltyToken);
+
+impl ERC1155<InMemoryContractStorage> for RoyaltyToken {}
+impl ERC1155Royalty<InMemoryContractStorage> for RoyaltyToken {}
+
+fn deploy() -> RoyaltyToken {
+    let token: RoyaltyToken = erc1155_fixtures::deploy();
+    ERC1155Royalty::init(&token);
+    token
+}
+
+#[test]
+fn test_no_royalty_by_default() {
+    let token = deploy();
+    assert_eq!(
+        token.royalty_info(1.into(), 1000.into()),
+        (ZERO_ADDRESS(), U256::zero())
+    );
+}
+
+#[test]
+fn test_default_and_token_royalty() {
+    let mut token = deploy();
+    token._set_default_royalty(account(2), 500.into());
+    token._set_token_royalty(7.into(), account(3), 1000.into());
+    assert_eq!(
+        token.royalty_info(1.into(), 1000.into()),
+        (account(2), 50.into())
+    );
+    assert_eq!(
+        token.royalty_info(7.into(), 1000.into()),
+        (account(3), 100.into())
+    );
+    token._reset_token_royalty(7.into());
+    assert_eq!(
+        token.royalty_info(7.into(), 1000.into()),
+        (account(2), 50.into())
+    );
+    token._delete_default_royalty();
+    assert_eq!(
+        token.royalty_info(7.into(), 1000.into()),
+        (ZERO_ADDRESS(), U256::zero())
+    );
+}
+
+#[test]
+fn test_royalty_rounds_down_without_overflow() {
+    let mut token = deploy();
+    token._set_default_royalty(account(2), 10000.into());
+    assert_eq!(
+        token.royalty_info(1.into(), U256::MAX),
+        (account(2), U256::MAX)
+    );
+    token._set_default_royalty(account(2), 3.into());
+    assert_eq!(
+        token.royalty_info(1.into(), 3333.into()),
+        (account(2), U256::zero())
+    );
+    assert_eq!(
+        token.royalty_info(1.into(), 3334.into()),
+        (account(2), U256::one())
+    );
+}
+
+#[test]
+fn test_invalid_royalty() {
+    let mut token = deploy();
+    let result = testing::catch_revert(|| token._set_default_royalty(account(2), 10001.into()));
+    assert_eq!(result, Err(ApiError::from(Error::InvalidRoyaltyFraction)));
+    let result =
+        testing::catch_revert(|| token._set_token_royalty(1.into(), ZERO_ADDRESS(), 100.into()));
+    assert_eq!(result, Err(ApiError::from(Error::InvalidRoyaltyReceiver)));
+}
@@ -0,0 +1,72 @@
+use crate::erc1155_fixtures::{self, account, last_event, test_token};
+use casper_types::{bytesrepr::Bytes, ApiError, Key, U256};
+use erc1155_crate::{
+    data::ZERO_ADDRESS,
+    erc165::ERC1155_SOULBOUND_INTERFACE_ID,
+    testing::{self, InMemoryContractStorage},
+    ERC1155Event, ERC1155Soulbound, Error, ERC1155, ERC165, TYPE_NF_BIT,
+};
+
+test_token!(SoulboundToken);
+
+impl ERC1155<InMemoryContractStorage> for SoulboundToken {
+    fn _before_token_transfer(
+        &mut self,
+        _operator: Key,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        _amounts: Vec<U256>,
+        _data: Bytes,
+    ) {
+        ERC1155Soulbound::_enforce_transferable(self, from, to, &ids);
+    }
+}
+impl ERC1155Soulbound<InMemoryContractStorage> for SoulboundToken {}
+impl ERC165<InMemoryContractStorage> for SoulboundToken {}
+
+fn deploy() -> (SoulboundToken, Key) {
+    let token: SoulboundToken = erc1155_fixtures::deploy();
+    ERC1155Soulbound::init(&token);
+    (token, account(1))
+}
+
+#[test]
+fn test_init_registers_interface() {
+    let (token, _) = deploy();
+    assert!(token.supports_interface(ERC1155_SOULBOUND_INTERFACE_ID));
+}
+
+#[test]
+fn test_lock_transfers() {
+    let (mut token, owner) = deploy();
+    let id = U256::one();
+    token._mint(owner, id, 10.into(), Bytes::new());
+    token.safe_transfer_from(owner, account(2), id, 1.into(), "".into());
+    token._lock_transfers(id);
+    assert!(!token.is_transferable(id));
+    let result = testing::catch_revert(|| {
+        token.safe_batch_transfer_from(owner, account(2), vec![id], vec![1.into()], "".into())
+    });
+    assert_eq!(result, Err(ApiError::from(Error::NonTransferable)));
+    token._mint(owner, id, 5.into(), Bytes::new());
+    token._burn(owner, id, 14.into());
+    assert_eq!(token.balance_of(owner, id), U256::zero());
+}
+
+#[test]
+fn test_lock_transfers_any_id() {
+    let (mut token, owner) = deploy();
+    let split_id = TYPE_NF_BIT | (U256::one() << 128) | U256::one();
+    assert!(token.is_transferable(9.into()));
+    assert!(token.is_transferable(split_id));
+    token._lock_transfers(split_id);
+    assert_eq!(last_event(), ERC1155Event::TransfersLocked { id: split_id });
+    assert!(!token.is_transferable(split_id));
+    assert!(token.is_transferable(9.into()));
+    let result =
+        testing::catch_revert(|| token._enforce_transferable(owner, account(2), &[split_id]));
+    assert_eq!(result, Err(ApiError::from(Error::NonTransferable)));
+    token._enforce_transferable(ZERO_ADDRESS(), account(2), &[split_id]);
+    token._enforce_transferable(owner, ZERO_ADDRESS(), &[split_id]);
+}
@@ -1,13 +1,256 @@
 use crate::erc1155_instance::ERC1155Instance;
-use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
+use casper_types::{
+    account::AccountHash, runtime_args, AccessRights, Key, RuntimeArgs, URef, U256,
+};
 use casperlabs_test_env::{TestContract, TestEnv};
 use common::keys::*;
+use erc1155_crate::{
+    erc165::{ERC1155_INTERFACE_ID, ERC1155_RECEIVER_INTERFACE_ID, ERC2981_INTERFACE_ID},
+    OperatorFilterMode, TokenKind, TYPE_NF_BIT,
+};
 fn deploy() -> (TestEnv, AccountHash, TestContract) {
     let env = TestEnv::new();
     let owner = env.next_user();
-    let contract = ERC1155Instance::new(&env, "ERC1155", owner, "".to_string());
+    let contract = ERC1155Instance::new(
+        &env,
+        "ERC1155",
+        owner,
+        "ERC1155".to_string(),
+        "ERC".to_string(),
+        "".to_string(),
+        "https://example.com/collection.json".to_string(),
+    );
+    // Registers ids 1, 2 and 3 as uncapped fungible types.
+    for _ in 0..3 {
+        contract.call_contract(
+            owner,
+            "create_token_type",
+            runtime_args! {
+                "kind" => TokenKind::Fungible as u8,
+                "metadata_uri" => String::new(),
+                "max_supply" => U256::zero(),
+                "transferable" => true
+            },
+            0,
+        );
+    }
     (env, owner, contract)
 }
+fn balance_of(
+    env: &TestEnv,
+    owner: AccountHash,
+    package_hash: [u8; 32],
+    account: Key,
+    id: U256,
+) -> U256 {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(BALANCE_OF),
+            "package_hash" => Key::Hash(package_hash),
+            "account"=>account,
+            "id"=>id
+        },
+        0,
+    );
+    env.query_account_named_key(owner, &[BALANCE_OF.into()])
+}
+fn has_role(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: [u8; 32],
+    role: &str,
+    account: Key,
+) -> bool {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(HAS_ROLE),
+            "package_hash" => Key::Hash(package_hash),
+            "role" => role.to_string(),
+            "account" => account
+        },
+        0,
+    );
+    env.query_account_named_key(sender, &[HAS_ROLE.into()])
+}
+fn paused(env: &TestEnv, sender: AccountHash, package_hash: [u8; 32]) -> bool {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(PAUSED),
+            "package_hash" => Key::Hash(package_hash),
+        },
+        0,
+    );
+    env.query_account_named_key(sender, &[PAUSED.into()])
+}
+fn owner_of_contract(env: &TestEnv, sender: AccountHash, package_hash: [u8; 32]) -> Key {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(OWNER),
+            "package_hash" => Key::Hash(package_hash),
+        },
+        0,
+    );
+    env.query_account_named_key(sender, &[OWNER.into()])
+}
+fn tokens_of_owner(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: [u8; 32],
+    owner: Key,
+    page: U256,
+    size: U256,
+) -> Vec<U256> {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(TOKENS_OF_OWNER),
+            "package_hash" => Key::Hash(package_hash),
+            "owner" => owner,
+            "page" => page,
+            "size" => size
+        },
+        0,
+    );
+    env.query_account_named_key(sender, &[TOKENS_OF_OWNER.into()])
+}
+fn holders_of(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: [u8; 32],
+    id: U256,
+    page: U256,
+    size: U256,
+) -> Vec<Key> {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(HOLDERS_OF),
+            "package_hash" => Key::Hash(package_hash),
+            "id" => id,
+            "page" => page,
+            "size" => size
+        },
+        0,
+    );
+    env.query_account_named_key(sender, &[HOLDERS_OF.into()])
+}
+fn allowance(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: [u8; 32],
+    owner: Key,
+    operator: Key,
+    id: U256,
+) -> U256 {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(ALLOWANCE),
+            "package_hash" => Key::Hash(package_hash),
+            "owner" => owner,
+            "operator" => operator,
+            "id" => id
+        },
+        0,
+    );
+    env.query_account_named_key(sender, &[ALLOWANCE.into()])
+}
+fn owner_of(env: &TestEnv, sender: AccountHash, package_hash: [u8; 32], id: U256) -> Key {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(OWNER_OF),
+            "package_hash" => Key::Hash(package_hash),
+            "id" => id
+        },
+        0,
+    );
+    env.query_account_named_key(sender, &[OWNER_OF.into()])
+}
+fn royalty_info(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: [u8; 32],
+    id: U256,
+    sale_price: U256,
+) -> (Key, U256) {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(ROYALTY_INFO),
+            "package_hash" => Key::Hash(package_hash),
+            "id" => id,
+            "sale_price" => sale_price
+        },
+        0,
+    );
+    env.query_account_named_key(sender, &[ROYALTY_INFO.into()])
+}
+fn supports_interface(
+    env: &TestEnv,
+    sender: AccountHash,
+    package_hash: [u8; 32],
+    interface_id: u32,
+) -> bool {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(SUPPORTS_INTERFACE),
+            "package_hash" => Key::Hash(package_hash),
+            "interface_id" => interface_id
+        },
+        0,
+    );
+    env.query_account_named_key(sender, &[SUPPORTS_INTERFACE.into()])
+}
+fn metadata(env: &TestEnv, sender: AccountHash, package_hash: [u8; 32], key: &str) -> String {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        sender,
+        runtime_args! {
+            "entrypoint" => String::from(key),
+            "package_hash" => Key::Hash(package_hash)
+        },
+        0,
+    );
+    env.query_account_named_key(sender, &[key.into()])
+}
 
 #[test]
 fn test_deploy() {
@@ -26,6 +269,7 @@ fn test_uri() {
             "entrypoint" => String::from(URI),
             "package_hash" => Key::Hash(contract.package_hash()),
             "owner"=>Key::from(owner),
+            "id"=>U256::from(1)
         },
         0,
     );
@@ -53,3 +297,892 @@ fn test_set_approval_for_all() {
     .unwrap();
     contract.set_approval_for_all(owner, operator, true);
 }
+// Transfers 10 units of id 1 as an operator whose approval lapses at block time 200.
+fn operator_transfer_at(time: u64) -> (TestEnv, AccountHash, Key, TestContract) {
+    let (env, owner, contract) = deploy();
+    let operator = env.next_user();
+    contract.call_contract(
+        owner,
+        "mint",
+        runtime_args! {
+            "to" => Key::Account(owner),
+            "id" => U256::one(),
+            "amount" => U256::from(100),
+            "data" => "0x00".to_string()
+        },
+        0,
+    );
+    contract.call_contract(
+        owner,
+        "set_approval_for_all_until",
+        runtime_args! {
+            "operator" => Key::Account(operator),
+            "expires_at" => 200u64
+        },
+        0,
+    );
+    contract.call_contract(
+        operator,
+        "safe_transfer_from",
+        runtime_args! {
+            "from" => Key::Account(owner),
+            "to" => Key::Account(operator),
+            "id" => U256::one(),
+            "amount" => U256::from(10),
+            "data" => "0x00".to_string()
+        },
+        time,
+    );
+    (env, owner, Key::Account(operator), contract)
+}
+#[test]
+fn test_approval_for_all_until() {
+    let (env, owner, operator, contract) = operator_transfer_at(199);
+    let contract = ERC1155Instance::contract_instance(contract);
+    let ret = balance_of(&env, owner, contract.package_hash(), operator, 1.into());
+    assert_eq!(ret, 10.into());
+}
+#[test]
+#[should_panic]
+fn test_approval_for_all_until_expired() {
+    operator_transfer_at(200);
+}
+#[test]
+fn test_safe_transfer_from_to_account() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let to = Key::Account(env.next_user());
+    contract.safe_transfer_from(
+        owner,
+        Key::Account(owner),
+        to,
+        1.into(),
+        0.into(),
+        "".to_string(),
+    );
+    contract.safe_batch_transfer_from(
+        owner,
+        Key::Account(owner),
+        to,
+        vec![1.into()],
+        vec![0.into()],
+        "".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_safe_transfer_from_to_non_receiver() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let to = Key::Hash(contract.package_hash());
+    contract.safe_transfer_from(
+        owner,
+        Key::Account(owner),
+        to,
+        1.into(),
+        0.into(),
+        "".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_safe_batch_transfer_from_to_non_receiver() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let to = Key::Hash(contract.package_hash());
+    contract.safe_batch_transfer_from(
+        owner,
+        Key::Account(owner),
+        to,
+        vec![1.into()],
+        vec![0.into()],
+        "".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_safe_transfer_from_to_invalid_receiver() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let to = Key::URef(URef::new([1; 32], AccessRights::READ));
+    contract.safe_transfer_from(
+        owner,
+        Key::Account(owner),
+        to,
+        1.into(),
+        0.into(),
+        "".to_string(),
+    );
+}
+#[test]
+fn test_approval_for_allow_listed_operator() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let operator = Key::Account(env.next_user());
+    contract.set_operator_filter_mode(owner, OperatorFilterMode::AllowList as u8);
+    contract.set_operator_listed(owner, operator, true);
+    contract.set_approval_for_all(owner, operator, true);
+}
+#[test]
+#[should_panic]
+fn test_approval_for_deny_listed_operator() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let operator = Key::Account(env.next_user());
+    contract.set_operator_filter_mode(owner, OperatorFilterMode::DenyList as u8);
+    contract.set_operator_listed(owner, operator, true);
+    contract.set_approval_for_all(owner, operator, true);
+}
+#[test]
+#[should_panic]
+fn test_set_operator_filter_mode_by_non_owner() {
+    let (env, _, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.set_operator_filter_mode(env.next_user(), OperatorFilterMode::DenyList as u8);
+}
+#[test]
+fn test_safe_transfer_from_with_allowance() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let (operator, to) = (env.next_user(), Key::Account(env.next_user()));
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+    contract.approve(owner, Key::Account(operator), 1.into(), 30.into());
+    contract.safe_transfer_from(
+        operator,
+        Key::Account(owner),
+        to,
+        1.into(),
+        10.into(),
+        "0x00".to_string(),
+    );
+    let ret = allowance(
+        &env,
+        owner,
+        contract.package_hash(),
+        Key::Account(owner),
+        Key::Account(operator),
+        1.into(),
+    );
+    assert_eq!(ret, 20.into());
+    let ret = balance_of(&env, owner, contract.package_hash(), to, 1.into());
+    assert_eq!(ret, 10.into());
+}
+#[test]
+#[should_panic]
+fn test_safe_transfer_from_exceeding_allowance() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let operator = env.next_user();
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+    contract.approve(owner, Key::Account(operator), 1.into(), 5.into());
+    contract.safe_transfer_from(
+        operator,
+        Key::Account(owner),
+        Key::Account(operator),
+        1.into(),
+        6.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+fn test_owner() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let ret: Key = owner_of_contract(&env, owner, contract.package_hash());
+    assert_eq!(ret, Key::Account(owner));
+}
+#[test]
+fn test_mint_by_owner() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let to: Key = Key::Account(env.next_user());
+    contract.mint(owner, to, 1.into(), 100.into(), "0x00".to_string());
+    contract.mint_batch(
+        owner,
+        Key::Account(owner),
+        vec![1.into(), 2.into()],
+        vec![10.into(), 20.into()],
+        "0x00".to_string(),
+    );
+    let ret: U256 = balance_of(&env, owner, contract.package_hash(), to, 1.into());
+    assert_eq!(ret, 100.into());
+    let ret: U256 = balance_of(
+        &env,
+        owner,
+        contract.package_hash(),
+        Key::Account(owner),
+        2.into(),
+    );
+    assert_eq!(ret, 20.into());
+}
+#[test]
+#[should_panic]
+fn test_mint_by_non_owner() {
+    let (env, _, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let user = env.next_user();
+    contract.mint(
+        user,
+        Key::Account(user),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_burn_by_non_approved() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let user = env.next_user();
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+    contract.burn(user, Key::Account(owner), 1.into(), 100.into());
+}
+#[test]
+fn test_burn_by_burner() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let user = env.next_user();
+    contract.mint(
+        owner,
+        Key::Account(user),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+    contract.burn(owner, Key::Account(user), 1.into(), 40.into());
+    let ret: U256 = balance_of(
+        &env,
+        owner,
+        contract.package_hash(),
+        Key::Account(user),
+        1.into(),
+    );
+    assert_eq!(ret, 60.into());
+}
+#[test]
+fn test_transfer_ownership() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let new_owner = env.next_user();
+    contract.transfer_ownership(owner, Key::Account(new_owner));
+    let ret: Key = owner_of_contract(&env, owner, contract.package_hash());
+    assert_eq!(ret, Key::Account(new_owner));
+}
+#[test]
+fn test_mint_by_new_owner() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let new_owner = env.next_user();
+    contract.transfer_ownership(owner, Key::Account(new_owner));
+    contract.mint(
+        new_owner,
+        Key::Account(new_owner),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+    let ret: U256 = balance_of(
+        &env,
+        owner,
+        contract.package_hash(),
+        Key::Account(new_owner),
+        1.into(),
+    );
+    assert_eq!(ret, 100.into());
+}
+#[test]
+#[should_panic]
+fn test_mint_after_transfer_ownership() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let new_owner = env.next_user();
+    contract.transfer_ownership(owner, Key::Account(new_owner));
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_mint_after_renounce_ownership() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.renounce_ownership(owner);
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_transfer_ownership_by_non_owner() {
+    let (env, _, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let user = env.next_user();
+    contract.transfer_ownership(user, Key::Account(user));
+}
+#[test]
+#[should_panic]
+fn test_transfer_ownership_after_renounce_ownership() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.renounce_ownership(owner);
+    contract.transfer_ownership(owner, Key::Account(owner));
+}
+#[test]
+fn test_deployer_roles() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    for role in [
+        "default_admin_role",
+        "minter_role",
+        "burner_role",
+        "pauser_role",
+        "uri_setter_role",
+    ] {
+        assert!(has_role(
+            &env,
+            owner,
+            contract.package_hash(),
+            role,
+            Key::Account(owner)
+        ));
+    }
+}
+#[test]
+fn test_grant_minter_role() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let minter = env.next_user();
+    contract.grant_role(owner, "minter_role", Key::Account(minter));
+    assert!(has_role(
+        &env,
+        owner,
+        contract.package_hash(),
+        "minter_role",
+        Key::Account(minter)
+    ));
+    contract.mint(
+        minter,
+        Key::Account(minter),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+    let ret: U256 = balance_of(
+        &env,
+        owner,
+        contract.package_hash(),
+        Key::Account(minter),
+        1.into(),
+    );
+    assert_eq!(ret, 100.into());
+}
+#[test]
+#[should_panic]
+fn test_mint_after_revoke_role() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let minter = env.next_user();
+    contract.grant_role(owner, "minter_role", Key::Account(minter));
+    contract.revoke_role(owner, "minter_role", Key::Account(minter));
+    contract.mint(
+        minter,
+        Key::Account(minter),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_grant_role_by_non_admin() {
+    let (env, _, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let user = env.next_user();
+    contract.grant_role(user, "minter_role", Key::Account(user));
+}
+#[test]
+fn test_renounce_role() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.renounce_role(owner, "minter_role", Key::Account(owner));
+    assert!(!has_role(
+        &env,
+        owner,
+        contract.package_hash(),
+        "minter_role",
+        Key::Account(owner)
+    ));
+}
+#[test]
+fn test_pause_unpause() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.pause(owner);
+    assert!(paused(&env, owner, contract.package_hash()));
+    contract.unpause(owner);
+    assert!(!paused(&env, owner, contract.package_hash()));
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_mint_while_paused() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.pause(owner);
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_safe_transfer_from_while_paused() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+    contract.pause(owner);
+    contract.safe_transfer_from(
+        owner,
+        Key::Account(owner),
+        Key::Account(env.next_user()),
+        1.into(),
+        10.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_burn_while_paused() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+    contract.pause(owner);
+    contract.burn(owner, Key::Account(owner), 1.into(), 10.into());
+}
+#[test]
+#[should_panic]
+fn test_pause_by_non_pauser() {
+    let (env, _, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.pause(env.next_user());
+}
+#[test]
+fn test_burn_own_tokens() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let holder = env.next_user();
+    contract.mint(
+        owner,
+        Key::Account(holder),
+        1.into(),
+        100.into(),
+        "0x00".to_string(),
+    );
+    contract.burn(holder, Key::Account(holder), 1.into(), 30.into());
+    let ret: U256 = balance_of(
+        &env,
+        owner,
+        contract.package_hash(),
+        Key::Account(holder),
+        1.into(),
+    );
+    assert_eq!(ret, 70.into());
+}
+#[test]
+fn test_enumeration_after_mint_and_burn() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let to: Key = Key::Account(env.next_user());
+    contract.mint_batch(
+        owner,
+        to,
+        vec![1.into(), 2.into(), 3.into()],
+        vec![10.into(), 20.into(), 30.into()],
+        "0x00".to_string(),
+    );
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        2.into(),
+        5.into(),
+        "0x00".to_string(),
+    );
+    let ret: Vec<U256> =
+        tokens_of_owner(&env, owner, contract.package_hash(), to, 0.into(), 2.into());
+    assert_eq!(ret, vec![1.into(), 2.into()]);
+    let ret: Vec<U256> =
+        tokens_of_owner(&env, owner, contract.package_hash(), to, 1.into(), 2.into());
+    assert_eq!(ret, vec![3.into()]);
+    let ret: Vec<Key> = holders_of(
+        &env,
+        owner,
+        contract.package_hash(),
+        2.into(),
+        0.into(),
+        10.into(),
+    );
+    assert_eq!(ret, vec![to, Key::Account(owner)]);
+    contract.burn(owner, Key::Account(owner), 2.into(), 5.into());
+    let ret: Vec<Key> = holders_of(
+        &env,
+        owner,
+        contract.package_hash(),
+        2.into(),
+        0.into(),
+        10.into(),
+    );
+    assert_eq!(ret, vec![to]);
+}
+#[test]
+fn test_mint_up_to_max_supply() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.set_max_supply(owner, 1.into(), 10.into(), false);
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        10.into(),
+        "0x00".to_string(),
+    );
+    let ret: U256 = balance_of(
+        &env,
+        owner,
+        contract.package_hash(),
+        Key::Account(owner),
+        1.into(),
+    );
+    assert_eq!(ret, 10.into());
+}
+#[test]
+#[should_panic]
+fn test_mint_beyond_max_supply() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.set_max_supply(owner, 1.into(), 10.into(), false);
+    contract.mint_batch(
+        owner,
+        Key::Account(owner),
+        vec![1.into(), 1.into()],
+        vec![6.into(), 5.into()],
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_set_max_supply_by_non_admin() {
+    let (env, _, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.set_max_supply(env.next_user(), 1.into(), 10.into(), false);
+}
+#[test]
+#[should_panic]
+fn test_mint_unregistered_token_type() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        99.into(),
+        1.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+fn test_mint_non_fungible_token_type() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.create_token_type(
+        owner,
+        TokenKind::NonFungible as u8,
+        "ipfs://token/4".to_string(),
+        0.into(),
+        true,
+    );
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        4.into(),
+        1.into(),
+        "0x00".to_string(),
+    );
+    let ret: U256 = balance_of(
+        &env,
+        owner,
+        contract.package_hash(),
+        Key::Account(owner),
+        4.into(),
+    );
+    assert_eq!(ret, 1.into());
+}
+#[test]
+#[should_panic]
+fn test_mint_non_fungible_amount() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.create_token_type(
+        owner,
+        TokenKind::NonFungible as u8,
+        "".to_string(),
+        0.into(),
+        true,
+    );
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        4.into(),
+        2.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_transfer_locked_token_type() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        1.into(),
+        10.into(),
+        "0x00".to_string(),
+    );
+    contract.lock_transfers(owner, 1.into());
+    contract.safe_transfer_from(
+        owner,
+        Key::Account(owner),
+        Key::Account(env.next_user()),
+        1.into(),
+        1.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_transfer_locked_non_fungible_item() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let item: U256 = TYPE_NF_BIT | (U256::one() << 128) | U256::one();
+    contract.mint_non_fungible(owner, item - 1, vec![Key::Account(owner)]);
+    contract.lock_transfers(owner, item);
+    contract.safe_transfer_from(
+        owner,
+        Key::Account(owner),
+        Key::Account(env.next_user()),
+        item,
+        1.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_lock_transfers_by_non_admin() {
+    let (env, _, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.lock_transfers(env.next_user(), 1.into());
+}
+#[test]
+#[should_panic]
+fn test_transfer_non_transferable_token_type() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.create_token_type(
+        owner,
+        TokenKind::Fungible as u8,
+        "".to_string(),
+        0.into(),
+        false,
+    );
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        4.into(),
+        10.into(),
+        "0x00".to_string(),
+    );
+    contract.safe_transfer_from(
+        owner,
+        Key::Account(owner),
+        Key::Account(env.next_user()),
+        4.into(),
+        1.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_create_token_type_by_non_admin() {
+    let (env, _, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.create_token_type(
+        env.next_user(),
+        TokenKind::Fungible as u8,
+        "".to_string(),
+        0.into(),
+        true,
+    );
+}
+#[test]
+fn test_mint_non_fungible() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let (first, second) = (Key::Account(env.next_user()), Key::Account(env.next_user()));
+    let type_id: U256 = TYPE_NF_BIT | (U256::one() << 128);
+    contract.mint_non_fungible(owner, type_id, vec![first, second]);
+    let ret: Key = owner_of(&env, owner, contract.package_hash(), type_id + 1);
+    assert_eq!(ret, first);
+    let ret: Key = owner_of(&env, owner, contract.package_hash(), type_id + 2);
+    assert_eq!(ret, second);
+    let ret: U256 = balance_of(&env, owner, contract.package_hash(), second, type_id + 2);
+    assert_eq!(ret, 1.into());
+}
+#[test]
+fn test_mint_split_fungible_and_non_fungible() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let user = Key::Account(env.next_user());
+    let fungible_id: U256 = U256::one() << 128;
+    let type_id: U256 = TYPE_NF_BIT | (U256::from(2) << 128);
+    contract.mint(owner, user, fungible_id, 100.into(), "0x00".to_string());
+    contract.mint_non_fungible(owner, type_id, vec![user]);
+    let ret: U256 = balance_of(&env, owner, contract.package_hash(), user, fungible_id);
+    assert_eq!(ret, 100.into());
+    let ret: U256 = balance_of(&env, owner, contract.package_hash(), user, type_id + 1);
+    assert_eq!(ret, 1.into());
+    let ret: Key = owner_of(&env, owner, contract.package_hash(), type_id + 1);
+    assert_eq!(ret, user);
+}
+#[test]
+#[should_panic]
+fn test_mint_non_fungible_item_directly() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let type_id: U256 = TYPE_NF_BIT | (U256::one() << 128);
+    contract.mint(
+        owner,
+        Key::Account(owner),
+        type_id + 1,
+        1.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+fn test_royalty_info() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let (artist, label) = (Key::Account(env.next_user()), Key::Account(env.next_user()));
+    contract.set_default_royalty(owner, label, 250.into());
+    contract.set_token_royalty(owner, 2.into(), artist, 1000.into());
+    let ret = royalty_info(&env, owner, contract.package_hash(), 1.into(), 10000.into());
+    assert_eq!(ret, (label, 250.into()));
+    let ret = royalty_info(&env, owner, contract.package_hash(), 2.into(), 10000.into());
+    assert_eq!(ret, (artist, 1000.into()));
+}
+#[test]
+#[should_panic]
+fn test_set_royalty_above_denominator() {
+    let (_, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.set_default_royalty(owner, Key::Account(owner), 10001.into());
+}
+#[test]
+#[should_panic]
+fn test_set_royalty_by_non_admin() {
+    let (env, _, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let user = env.next_user();
+    contract.set_token_royalty(user, 1.into(), Key::Account(user), 500.into());
+}
+#[test]
+fn test_supports_interface() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    for interface_id in [ERC1155_INTERFACE_ID, ERC2981_INTERFACE_ID] {
+        assert!(supports_interface(
+            &env,
+            owner,
+            contract.package_hash(),
+            interface_id
+        ));
+    }
+    for interface_id in [ERC1155_RECEIVER_INTERFACE_ID, 0xffffffff] {
+        assert!(!supports_interface(
+            &env,
+            owner,
+            contract.package_hash(),
+            interface_id
+        ));
+    }
+}
+#[test]
+fn test_contract_metadata() {
+    let (env, owner, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    let package_hash = contract.package_hash();
+    assert_eq!(metadata(&env, owner, package_hash, NAME), "ERC1155");
+    assert_eq!(metadata(&env, owner, package_hash, SYMBOL), "ERC");
+    assert_eq!(
+        metadata(&env, owner, package_hash, CONTRACT_URI),
+        "https://example.com/collection.json"
+    );
+    contract.set_contract_uri(owner, "ipfs://collection".to_string());
+    assert_eq!(
+        metadata(&env, owner, package_hash, CONTRACT_URI),
+        "ipfs://collection"
+    );
+}
+#[test]
+#[should_panic]
+fn test_set_contract_uri_by_non_admin() {
+    let (env, _, contract) = deploy();
+    let contract = ERC1155Instance::contract_instance(contract);
+    contract.set_contract_uri(env.next_user(), "ipfs://collection".to_string());
+}
@@ -0,0 +1,136 @@
+use crate::erc1155_fixtures::{self, account, test_token};
+use casper_types::{bytesrepr::Bytes, ApiError, Key, U256};
+use core::convert::TryFrom;
+use erc1155_crate::{
+    testing::{self, InMemoryContractStorage},
+    ERC1155Burnable, ERC1155Capped, ERC1155Soulbound, ERC1155Supply, ERC1155TokenTypes,
+    ERC1155URIStorage, Error, TokenKind, ERC1155,
+};
+
+test_token!(TypedToken);
+
+impl ERC1155<InMemoryContractStorage> for TypedToken {
+    fn _before_token_transfer(
+        &mut self,
+        _operator: Key,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        _data: Bytes,
+    ) {
+        ERC1155Soulbound::_enforce_transferable(self, from, to, &ids);
+        ERC1155TokenTypes::_enforce_token_types(self, from, &ids, &amounts);
+        ERC1155Supply::_update_supply(self, from, to, &ids, &amounts);
+        ERC1155Capped::_enforce_max_supply(self, from, &ids);
+    }
+}
+impl ERC1155Supply<InMemoryContractStorage> for TypedToken {}
+impl ERC1155Burnable<InMemoryContractStorage> for TypedToken {}
+impl ERC1155Capped<InMemoryContractStorage> for TypedToken {}
+impl ERC1155URIStorage<InMemoryContractStorage> for TypedToken {}
+impl ERC1155Soulbound<InMemoryContractStorage> for TypedToken {}
+impl ERC1155TokenTypes<InMemoryContractStorage> for TypedToken {}
+
+fn deploy() -> (TypedToken, Key) {
+    let token: TypedToken = erc1155_fixtures::deploy();
+    ERC1155Supply::init(&token);
+    ERC1155Capped::init(&token);
+    ERC1155URIStorage::init(&token);
+    ERC1155Soulbound::init(&token);
+    ERC1155TokenTypes::init(&token);
+    (token, account(1))
+}
+
+#[test]
+fn test_create_token_type_assigns_sequential_ids() {
+    let (mut token, _) = deploy();
+    let fungible = token._create_token_type(TokenKind::Fungible, "".into(), 0.into(), true);
+    let non_fungible = token._create_token_type(
+        TokenKind::NonFungible,
+        "ipfs://token/2".into(),
+        0.into(),
+        true,
+    );
+    assert_eq!(fungible, 1.into());
+    assert_eq!(non_fungible, 2.into());
+    assert_eq!(token.token_type_count(), 2.into());
+    assert_eq!(token.token_kind(1.into()), Some(TokenKind::Fungible));
+    assert_eq!(token.token_kind(2.into()), Some(TokenKind::NonFungible));
+    assert_eq!(token.token_kind(3.into()), None);
+    assert_eq!(token.max_supply(1.into()), U256::zero());
+    assert_eq!(token.max_supply(2.into()), U256::one());
+    assert!(token.is_max_supply_locked(2.into()));
+    assert_eq!(ERC1155URIStorage::uri(&token, 2.into()), "ipfs://token/2");
+}
+
+#[test]
+fn test_create_fungible_token_type_with_max_supply() {
+    let (mut token, owner) = deploy();
+    let id = token._create_token_type(TokenKind::Fungible, "".into(), 10.into(), true);
+    token._mint(owner, id, 10.into(), Bytes::new());
+    let result = testing::catch_revert(|| token._mint(owner, id, 1.into(), Bytes::new()));
+    assert_eq!(result, Err(ApiError::from(Error::MaxSupplyExceeded)));
+}
+
+#[test]
+fn test_create_non_fungible_token_type_with_max_supply() {
+    let (mut token, _) = deploy();
+    let result = testing::catch_revert(|| {
+        token._create_token_type(TokenKind::NonFungible, "".into(), 2.into(), true)
+    });
+    assert_eq!(result, Err(ApiError::from(Error::NonFungibleAmount)));
+}
+
+#[test]
+fn test_invalid_token_kind() {
+    assert_eq!(TokenKind::try_from(1).ok(), Some(TokenKind::NonFungible));
+    assert_eq!(
+        TokenKind::try_from(2).map_err(ApiError::from),
+        Err(ApiError::from(Error::InvalidTokenKind))
+    );
+}
+
+#[test]
+fn test_mint_unregistered_token_type() {
+    let (mut token, owner) = deploy();
+    let result = testing::catch_revert(|| token._mint(owner, 1.into(), 1.into(), Bytes::new()));
+    assert_eq!(result, Err(ApiError::from(Error::UnregisteredTokenType)));
+}
+
+#[test]
+fn test_mint_non_fungible_token_type() {
+    let (mut token, owner) = deploy();
+    let id = token._create_token_type(TokenKind::NonFungible, "".into(), 0.into(), true);
+    let result = testing::catch_revert(|| token._mint(owner, id, 2.into(), Bytes::new()));
+    assert_eq!(result, Err(ApiError::from(Error::NonFungibleAmount)));
+    token._mint(owner, id, 1.into(), Bytes::new());
+    assert_eq!(token.balance_of(owner, id), 1.into());
+    let result = testing::catch_revert(|| token._mint(account(2), id, 1.into(), Bytes::new()));
+    assert_eq!(result, Err(ApiError::from(Error::NonFungibleAlreadyMinted)));
+}
+
+#[test]
+fn test_burned_non_fungible_token_type_cannot_be_minted_again() {
+    let (mut token, owner) = deploy();
+    let id = token._create_token_type(TokenKind::NonFungible, "".into(), 0.into(), true);
+    token._mint(owner, id, 1.into(), Bytes::new());
+    token.burn(owner, id, 1.into());
+    assert_eq!(token.total_supply(id), U256::zero());
+    let result = testing::catch_revert(|| token._mint(account(2), id, 1.into(), Bytes::new()));
+    assert_eq!(result, Err(ApiError::from(Error::NonFungibleAlreadyMinted)));
+}
+
+#[test]
+fn test_non_transferable_token_type() {
+    let (mut token, owner) = deploy();
+    let id = token._create_token_type(TokenKind::Fungible, "".into(), 0.into(), false);
+    token._mint(owner, id, 10.into(), Bytes::new());
+    assert!(!token.is_transferable(id));
+    let result = testing::catch_revert(|| {
+        token.safe_transfer_from(owner, account(2), id, 1.into(), "".into())
+    });
+    assert_eq!(result, Err(ApiError::from(Error::NonTransferable)));
+    token.burn(owner, id, 10.into());
+    assert_eq!(token.total_supply(id), U256::zero());
+}
@@ -0,0 +1,667 @@
+use crate::erc1155_fixtures::{self, account, last_event, test_token};
+use casper_types::{bytesrepr::Bytes, ApiError, CLValue, ContractPackageHash, Key, U256};
+use erc1155_crate::{
+    ces::{self, EventsMode, Schemas},
+    data::{self, ERC1155_RECEIVED, ON_ERC1155_RECEIVED},
+    erc165,
+    testing::{self, InMemoryContractStorage},
+    ERC1155Burnable, ERC1155Capped, ERC1155Enumerable, ERC1155Event, ERC1155Supply,
+    ERC1155URIStorage, Error, Pausable, ERC1155, ERC165,
+};
+use erc1155_events::{decode, decode_ces, DecodeError};
+
+test_token!(pub(crate) Token);
+
+impl ERC1155<InMemoryContractStorage> for Token {
+    fn _before_token_transfer(
+        &mut self,
+        _operator: Key,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        _data: Bytes,
+    ) {
+        ERC1155Supply::_update_supply(self, from, to, &ids, &amounts);
+        ERC1155Capped::_enforce_max_supply(self, from, &ids);
+    }
+    fn _after_token_transfer(
+        &mut self,
+        _operator: Key,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        _amounts: Vec<U256>,
+        _data: Bytes,
+    ) {
+        ERC1155Enumerable::_update_enumeration(self, from, to, &ids);
+    }
+}
+impl ERC1155Supply<InMemoryContractStorage> for Token {}
+impl ERC1155Burnable<InMemoryContractStorage> for Token {}
+impl ERC1155Enumerable<InMemoryContractStorage> for Token {}
+impl ERC1155Capped<InMemoryContractStorage> for Token {}
+impl ERC165<InMemoryContractStorage> for Token {}
+impl Pausable<InMemoryContractStorage> for Token {}
+impl ERC1155URIStorage<InMemoryContractStorage> for Token {}
+
+pub(crate) fn deploy() -> (Token, Key) {
+    deploy_with(EventsMode::Native)
+}
+
+pub(crate) fn deploy_with(events_mode: EventsMode) -> (Token, Key) {
+    let token: Token = erc1155_fixtures::deploy_with(testing::contract(), events_mode);
+    ERC1155Supply::init(&token);
+    ERC1155Enumerable::init(&token);
+    ERC1155Capped::init(&token);
+    (token, account(1))
+}
+
+#[test]
+fn test_mint_and_balance() {
+    let (mut token, owner) = deploy();
+    token._mint(owner, 1.into(), 100.into(), Bytes::new());
+    assert_eq!(token.balance_of(owner, 1.into()), 100.into());
+    assert_eq!(token.total_supply(1.into()), 100.into());
+    assert_eq!(
+        token.balance_of_batch(vec![owner, account(2)], vec![1.into(), 1.into()]),
+        vec![100.into(), U256::zero()]
+    );
+}
+
+#[test]
+fn test_mint_emits_event() {
+    let (mut token, owner) = deploy();
+    let before = testing::uref_values().len();
+    token._mint(owner, 1.into(), 100.into(), Bytes::new());
+    assert_eq!(testing::uref_values().len(), before + 1);
+    assert_eq!(
+        last_event(),
+        ERC1155Event::TransferSingle {
+            operator: owner,
+            from: Key::Hash([0; 32]),
+            to: owner,
+            id: 1.into(),
+            amount: 100.into(),
+        }
+    );
+}
+
+#[test]
+fn test_safe_transfer_from() {
+    let (mut token, owner) = deploy();
+    let recipient = account(2);
+    token._mint(owner, 1.into(), 100.into(), Bytes::new());
+    token.safe_transfer_from(owner, recipient, 1.into(), 40.into(), "".into());
+    assert_eq!(token.balance_of(owner, 1.into()), 60.into());
+    assert_eq!(token.balance_of(recipient, 1.into()), 40.into());
+    assert_eq!(token.total_supply(1.into()), 100.into());
+}
+
+#[test]
+fn test_safe_transfer_from_insufficient_balance() {
+    let (mut token, owner) = deploy();
+    token._mint(owner, 1.into(), 10.into(), Bytes::new());
+    let result = testing::catch_revert(|| {
+        token.safe_transfer_from(owner, account(2), 1.into(), 11.into(), "".into())
+    });
+    assert_eq!(result, Err(ApiError::from(Error::InsufficientBalance)));
+}
+
+#[test]
+fn test_safe_transfer_from_without_approval() {
+    let (mut token, owner) = deploy();
+    token._mint(owner, 1.into(), 10.into(), Bytes::new());
+    testing::set_caller(account(3));
+    let result = testing::catch_revert(|| {
+        token.safe_transfer_from(owner, account(3), 1.into(), 1.into(), "".into())
+    });
+    assert_eq!(result, Err(ApiError::from(Error::NotOwnerNotApproved)));
+}
+
+#[test]
+fn test_safe_transfer_from_by_operator() {
+    let (mut token, owner) = deploy();
+    let operator = account(3);
+    token._mint(owner, 1.into(), 10.into(), Bytes::new());
+    token.set_approval_for_all(operator, true);
+    assert!(token.is_approved_for_all(owner, operator));
+    testing::set_caller(operator);
+    token.safe_transfer_from(owner, operator, 1.into(), 4.into(), "".into());
+    assert_eq!(token.balance_of(operator, 1.into()), 4.into());
+}
+
+#[test]
+fn test_safe_transfer_from_with_allowance() {
+    let (mut token, owner) = deploy();
+    let operator = account(3);
+    token._mint(owner, 1.into(), 10.into(), Bytes::new());
+    token.approve(operator, 1.into(), 5.into());
+    testing::set_caller(operator);
+    token.safe_transfer_from(owner, account(2), 1.into(), 3.into(), "".into());
+    assert_eq!(token.balance_of(account(2), 1.into()), 3.into());
+    assert_eq!(token.allowance(owner, operator, 1.into()), 2.into());
+    let result = testing::catch_revert(|| {
+        token.safe_transfer_from(owner, account(2), 1.into(), 3.into(), "".into())
+    });
+    assert_eq!(result, Err(ApiError::from(Error::InsufficientAllowance)));
+}
+
+#[test]
+fn test_safe_batch_transfer_from_with_allowance() {
+    let (mut token, owner) = deploy();
+    let operator = account(3);
+    token._mint_batch(
+        owner,
+        vec![1.into(), 2.into()],
+        vec![10.into(), 10.into()],
+        Bytes::new(),
+    );
+    token.approve(operator, 1.into(), 4.into());
+    testing::set_caller(operator);
+    let result = testing::catch_revert(|| {
+        token.safe_batch_transfer_from(
+            owner,
+            account(2),
+            vec![1.into(), 2.into()],
+            vec![4.into(), 1.into()],
+            "".into(),
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::NotOwnerNotApproved)));
+    testing::set_caller(owner);
+    token.approve(operator, 2.into(), 1.into());
+    testing::set_caller(operator);
+    token.safe_batch_transfer_from(
+        owner,
+        account(2),
+        vec![1.into(), 2.into()],
+        vec![4.into(), 1.into()],
+        "".into(),
+    );
+    assert_eq!(token.allowance(owner, operator, 1.into()), U256::zero());
+    assert_eq!(token.allowance(owner, operator, 2.into()), U256::zero());
+    assert_eq!(token.balance_of(account(2), 2.into()), 1.into());
+}
+
+#[test]
+fn test_approve_self() {
+    let (mut token, owner) = deploy();
+    let result = testing::catch_revert(|| token.approve(owner, 1.into(), 1.into()));
+    assert_eq!(result, Err(ApiError::from(Error::SettingApprovalForSelf)));
+}
+
+#[test]
+fn test_safe_batch_transfer_from_length_mismatch() {
+    let (mut token, owner) = deploy();
+    token._mint(owner, 1.into(), 10.into(), Bytes::new());
+    let result = testing::catch_revert(|| {
+        token.safe_batch_transfer_from(
+            owner,
+            account(2),
+            vec![1.into(), 2.into()],
+            vec![1.into()],
+            "".into(),
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::MismatchIdsAndLength)));
+}
+
+#[test]
+fn test_burn() {
+    let (mut token, owner) = deploy();
+    token._mint_batch(
+        owner,
+        vec![1.into(), 2.into()],
+        vec![10.into(), 20.into()],
+        Bytes::new(),
+    );
+    token.burn(owner, 1.into(), 10.into());
+    assert_eq!(token.balance_of(owner, 1.into()), U256::zero());
+    assert!(!token.exists(1.into()));
+    assert_eq!(token.total_supply_all(), 20.into());
+}
+
+#[test]
+fn test_burn_exceeding_balance() {
+    let (mut token, owner) = deploy();
+    token._mint(owner, 1.into(), 10.into(), Bytes::new());
+    let result = testing::catch_revert(|| token.burn(owner, 1.into(), 11.into()));
+    assert_eq!(result, Err(ApiError::from(Error::BurnAmountExceedsBal)));
+}
+
+#[test]
+fn test_mint_to_accepting_contract() {
+    let (mut token, _) = deploy();
+    let receiver = ContractPackageHash::new([7; 32]);
+    testing::register_contract(receiver, |entry_point, _| {
+        assert_eq!(entry_point, ON_ERC1155_RECEIVED);
+        CLValue::from_t(ERC1155_RECEIVED).unwrap()
+    });
+    token._mint(
+        Key::Hash(receiver.value()),
+        1.into(),
+        5.into(),
+        Bytes::new(),
+    );
+    assert_eq!(
+        token.balance_of(Key::Hash(receiver.value()), 1.into()),
+        5.into()
+    );
+}
+
+#[test]
+fn test_mint_to_rejecting_contract() {
+    let (mut token, _) = deploy();
+    let receiver = ContractPackageHash::new([7; 32]);
+    testing::register_contract(receiver, |_, _| CLValue::from_t(0u32).unwrap());
+    let result = testing::catch_revert(|| {
+        token._mint(
+            Key::Hash(receiver.value()),
+            1.into(),
+            5.into(),
+            Bytes::new(),
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::Erc115RejectedToken1)));
+}
+
+#[test]
+fn test_transfer_batch_event_round_trip() {
+    let (mut token, owner) = deploy();
+    token._mint_batch(
+        owner,
+        vec![1.into(), 23.into()],
+        vec![12.into(), 3.into()],
+        Bytes::new(),
+    );
+    assert_eq!(
+        last_event(),
+        ERC1155Event::TransferBatch {
+            operator: owner,
+            from: Key::Hash([0; 32]),
+            to: owner,
+            ids: vec![1.into(), 23.into()],
+            amounts: vec![12.into(), 3.into()],
+        }
+    );
+}
+
+#[test]
+fn test_approval_for_all_event_round_trip() {
+    let (mut token, owner) = deploy();
+    token.set_approval_for_all(account(3), true);
+    assert_eq!(
+        last_event(),
+        ERC1155Event::ApprovalForAll {
+            owner,
+            operator: account(3),
+            approved: true,
+            expires_at: 0,
+        }
+    );
+}
+
+#[test]
+fn test_approval_for_all_until_expires() {
+    let (mut token, owner) = deploy();
+    let operator = account(3);
+    token._mint(owner, 1.into(), 10.into(), Bytes::new());
+    testing::set_blocktime(100);
+    token.set_approval_for_all_until(operator, 200);
+    assert_eq!(
+        last_event(),
+        ERC1155Event::ApprovalForAll {
+            owner,
+            operator,
+            approved: true,
+            expires_at: 200,
+        }
+    );
+    testing::set_blocktime(199);
+    assert!(token.is_approved_for_all(owner, operator));
+    testing::set_caller(operator);
+    token.safe_transfer_from(owner, operator, 1.into(), 4.into(), "".into());
+    testing::set_blocktime(200);
+    assert!(!token.is_approved_for_all(owner, operator));
+    let result = testing::catch_revert(|| {
+        token.safe_transfer_from(owner, operator, 1.into(), 4.into(), "".into())
+    });
+    assert_eq!(result, Err(ApiError::from(Error::NotOwnerNotApproved)));
+}
+
+#[test]
+fn test_approval_for_all_until_in_past() {
+    let (mut token, _) = deploy();
+    testing::set_blocktime(100);
+    let result = testing::catch_revert(|| token.set_approval_for_all_until(account(3), 100));
+    assert_eq!(result, Err(ApiError::from(Error::InvalidApprovalExpiry)));
+}
+
+#[test]
+fn test_set_approval_for_all_clears_expiry() {
+    let (mut token, owner) = deploy();
+    testing::set_blocktime(100);
+    token.set_approval_for_all_until(account(3), 200);
+    token.set_approval_for_all(account(3), true);
+    testing::set_blocktime(300);
+    assert!(token.is_approved_for_all(owner, account(3)));
+}
+
+#[test]
+fn test_approval_event_round_trip() {
+    let (mut token, owner) = deploy();
+    token.approve(account(3), 7.into(), 2.into());
+    assert_eq!(
+        last_event(),
+        ERC1155Event::Approval {
+            owner,
+            operator: account(3),
+            id: 7.into(),
+            amount: 2.into(),
+        }
+    );
+}
+
+#[test]
+fn test_decode_unversioned_event() {
+    let mut fields = std::collections::BTreeMap::new();
+    fields.insert("event_type".to_string(), "TransferSingle".to_string());
+    assert_eq!(
+        decode(&fields).unwrap_err(),
+        DecodeError::UnsupportedVersion(None)
+    );
+}
+
+#[test]
+fn test_ces_schemas_registered_at_init() {
+    deploy_with(EventsMode::CES);
+    assert_eq!(
+        testing::get_key::<Schemas>(ces::EVENTS_SCHEMA),
+        Some(ces::schemas())
+    );
+    assert_eq!(testing::get_key::<u32>(ces::EVENTS_LENGTH), Some(0));
+}
+
+#[test]
+fn test_ces_events_appended_in_order() {
+    let (mut token, owner) = deploy_with(EventsMode::CES);
+    let urefs = testing::uref_values().len();
+    token._mint(owner, 1.into(), 10.into(), Bytes::new());
+    token.set_approval_for_all(account(3), true);
+    assert_eq!(testing::uref_values().len(), urefs);
+    assert_eq!(testing::get_key::<u32>(ces::EVENTS_LENGTH), Some(2));
+    let events = testing::Dict::instance(ces::EVENTS);
+    let event = |index: &str| decode_ces(&events.get::<Bytes>(index).unwrap()).unwrap();
+    assert_eq!(
+        event("0"),
+        ERC1155Event::TransferSingle {
+            operator: owner,
+            from: Key::Hash([0; 32]),
+            to: owner,
+            id: 1.into(),
+            amount: 10.into(),
+        }
+    );
+    assert_eq!(
+        event("1"),
+        ERC1155Event::ApprovalForAll {
+            owner,
+            operator: account(3),
+            approved: true,
+            expires_at: 0,
+        }
+    );
+}
+
+#[test]
+fn test_ces_mode_writes_no_urefs() {
+    let (mut token, owner) = deploy_with(EventsMode::CES);
+    let urefs = testing::uref_values().len();
+    token._pause();
+    token._unpause();
+    assert_eq!(testing::uref_values().len(), urefs);
+    let events = testing::Dict::instance(ces::EVENTS);
+    let event = |index: &str| decode_ces(&events.get::<Bytes>(index).unwrap()).unwrap();
+    assert_eq!(event("0"), ERC1155Event::Paused { account: owner });
+    assert_eq!(event("1"), ERC1155Event::Unpaused { account: owner });
+}
+
+#[test]
+fn test_ces_schema_for_every_event() {
+    let role = String::from("minter");
+    let events = [
+        ERC1155Event::TransferBatch {
+            operator: account(1),
+            from: account(2),
+            to: account(3),
+            ids: vec![1.into(), 2.into()],
+            amounts: vec![3.into(), 4.into()],
+        },
+        ERC1155Event::TransferSingle {
+            operator: account(1),
+            from: account(2),
+            to: account(3),
+            id: 1.into(),
+            amount: 2.into(),
+        },
+        ERC1155Event::ApprovalForAll {
+            owner: account(1),
+            operator: account(2),
+            approved: true,
+            expires_at: 5,
+        },
+        ERC1155Event::URI {
+            value: "https://example.com/1.json".into(),
+            id: 1.into(),
+        },
+        ERC1155Event::BaseURI {
+            value: "https://example.com/{id}.json".into(),
+        },
+        ERC1155Event::Approval {
+            owner: account(1),
+            operator: account(2),
+            id: 1.into(),
+            amount: 2.into(),
+        },
+        ERC1155Event::OwnershipTransferred {
+            previous_owner: account(1),
+            new_owner: account(2),
+        },
+        ERC1155Event::RoleGranted {
+            role: role.clone(),
+            account: account(2),
+            sender: account(1),
+        },
+        ERC1155Event::RoleRevoked {
+            role: role.clone(),
+            account: account(2),
+            sender: account(1),
+        },
+        ERC1155Event::RoleAdminChanged {
+            role,
+            previous_admin_role: "admin".into(),
+            new_admin_role: "owner".into(),
+        },
+        ERC1155Event::Paused {
+            account: account(1),
+        },
+        ERC1155Event::Unpaused {
+            account: account(1),
+        },
+        ERC1155Event::OperatorFilterModeUpdated { mode: 2 },
+        ERC1155Event::OperatorFilterUpdated {
+            operator: account(2),
+            listed: true,
+        },
+        ERC1155Event::TransfersLocked { id: 1.into() },
+    ];
+    let schemas = ces::schemas();
+    assert_eq!(schemas.0.len(), events.len());
+    for event in events.iter() {
+        let bytes = ces::encode(event);
+        let decoded = decode_ces(&bytes).unwrap();
+        assert_eq!(&decoded, event);
+        let name = match event {
+            ERC1155Event::ApprovalForAll { .. } => "ApprovalForAll".to_string(),
+            _ => event.type_name(),
+        };
+        assert!(schemas.0.contains_key(&name), "{}", name);
+    }
+}
+
+#[test]
+fn test_set_uri_emits_template() {
+    let (mut token, _) = deploy();
+    ERC1155URIStorage::init(&token);
+    token._set_uri("ipfs://collection/{id}.json".into());
+    assert_eq!(
+        last_event(),
+        ERC1155Event::BaseURI {
+            value: "ipfs://collection/{id}.json".into(),
+        }
+    );
+    assert_eq!(
+        ERC1155URIStorage::uri(&token, 1.into()),
+        "ipfs://collection/0000000000000000000000000000000000000000000000000000000000000001.json"
+    );
+}
+
+#[test]
+fn test_tokens_of_owner_pages() {
+    let (mut token, owner) = deploy();
+    token._mint_batch(
+        owner,
+        vec![1.into(), 2.into(), 3.into()],
+        vec![1.into(), 1.into(), 1.into()],
+        Bytes::new(),
+    );
+    assert_eq!(token.token_count(owner), 3.into());
+    assert_eq!(
+        token.tokens_of_owner(owner, 0.into(), 2.into()),
+        vec![U256::from(1), U256::from(2)]
+    );
+    assert_eq!(
+        token.tokens_of_owner(owner, 1.into(), 2.into()),
+        vec![U256::from(3)]
+    );
+    assert!(token.tokens_of_owner(owner, 2.into(), 2.into()).is_empty());
+}
+
+#[test]
+fn test_enumeration_follows_zero_transitions() {
+    let (mut token, owner) = deploy();
+    let recipient = account(2);
+    token._mint_batch(
+        owner,
+        vec![1.into(), 2.into(), 3.into()],
+        vec![10.into(), 10.into(), 10.into()],
+        Bytes::new(),
+    );
+    // A partial transfer adds the recipient without removing the sender.
+    token.safe_transfer_from(owner, recipient, 1.into(), 4.into(), "".into());
+    assert_eq!(
+        token.holders_of(1.into(), 0.into(), 10.into()),
+        vec![owner, recipient]
+    );
+    // Emptying a balance swaps the last id into the freed slot.
+    token.safe_transfer_from(owner, recipient, 1.into(), 6.into(), "".into());
+    assert_eq!(
+        token.tokens_of_owner(owner, 0.into(), 10.into()),
+        vec![U256::from(3), U256::from(2)]
+    );
+    assert_eq!(
+        token.holders_of(1.into(), 0.into(), 10.into()),
+        vec![recipient]
+    );
+    assert_eq!(token.holder_count(1.into()), 1.into());
+    token.burn(owner, 2.into(), 10.into());
+    assert_eq!(token.token_count(owner), 1.into());
+    assert_eq!(token.holder_count(2.into()), U256::zero());
+}
+
+#[test]
+fn test_tokens_of_owner_invalid_page_size() {
+    let (token, owner) = deploy();
+    let result = testing::catch_revert(|| token.tokens_of_owner(owner, 0.into(), 0.into()));
+    assert_eq!(result, Err(ApiError::from(Error::InvalidPageSize)));
+    let result = testing::catch_revert(|| token.holders_of(1.into(), 0.into(), 101.into()));
+    assert_eq!(result, Err(ApiError::from(Error::InvalidPageSize)));
+}
+
+#[test]
+fn test_remaining_supply() {
+    let (mut token, owner) = deploy();
+    assert_eq!(token.remaining_supply(1.into()), U256::MAX);
+    token._set_max_supply(1.into(), 10.into(), false);
+    token._mint(owner, 1.into(), 4.into(), Bytes::new());
+    assert_eq!(token.max_supply(1.into()), 10.into());
+    assert_eq!(token.remaining_supply(1.into()), 6.into());
+    token.burn(owner, 1.into(), 4.into());
+    assert_eq!(token.remaining_supply(1.into()), 10.into());
+}
+
+#[test]
+fn test_mint_batch_with_repeated_id_beyond_max_supply() {
+    let (mut token, owner) = deploy();
+    token._set_max_supply(1.into(), 10.into(), false);
+    let result = testing::catch_revert(|| {
+        token._mint_batch(
+            owner,
+            vec![1.into(), 1.into()],
+            vec![6.into(), 5.into()],
+            Bytes::new(),
+        )
+    });
+    assert_eq!(result, Err(ApiError::from(Error::MaxSupplyExceeded)));
+}
+
+#[test]
+fn test_immutable_max_supply() {
+    let (mut token, _) = deploy();
+    token._set_max_supply(1.into(), 10.into(), true);
+    assert!(token.is_max_supply_locked(1.into()));
+    let result = testing::catch_revert(|| token._set_max_supply(1.into(), 20.into(), false));
+    assert_eq!(result, Err(ApiError::from(Error::MaxSupplyLocked)));
+}
+
+#[test]
+fn test_max_supply_below_total_supply() {
+    let (mut token, owner) = deploy();
+    token._mint(owner, 1.into(), 5.into(), Bytes::new());
+    let result = testing::catch_revert(|| token._set_max_supply(1.into(), 4.into(), false));
+    assert_eq!(
+        result,
+        Err(ApiError::from(Error::MaxSupplyBelowTotalSupply))
+    );
+}
+
+#[test]
+fn test_extensions_register_interfaces() {
+    let (token, _) = deploy();
+    for interface_id in [
+        erc165::ERC165_INTERFACE_ID,
+        erc165::ERC1155_INTERFACE_ID,
+        erc165::ERC1155_METADATA_URI_INTERFACE_ID,
+        erc165::ERC1155_SUPPLY_INTERFACE_ID,
+        erc165::ERC1155_ENUMERABLE_INTERFACE_ID,
+        erc165::ERC1155_CAPPED_INTERFACE_ID,
+    ] {
+        assert!(token.supports_interface(interface_id));
+    }
+    assert!(!token.supports_interface(erc165::ERC2981_INTERFACE_ID));
+    assert!(!token.supports_interface(0xffffffff));
+    // Registering again does not duplicate the entry.
+    erc165::register_interface(erc165::ERC1155_SUPPLY_INTERFACE_ID);
+    assert_eq!(data::supported_interfaces().len(), 5);
+}
+
+#[test]
+fn test_contract_metadata() {
+    let (mut token, _) = deploy();
+    assert_eq!(token.name(), "Token");
+    assert_eq!(token.symbol(), "TKN");
+    assert_eq!(token.contract_uri(), "");
+    token._set_contract_uri("ipfs://collection".into());
+    assert_eq!(token.contract_uri(), "ipfs://collection");
+}
@@ -3,3 +3,30 @@ pub mod erc1155_tests;
 
 #[cfg(test)]
 pub mod erc1155_instance;
+
+#[cfg(test)]
+pub mod erc1155_fixtures;
+
+#[cfg(test)]
+pub mod erc1155_unit_tests;
+
+#[cfg(test)]
+pub mod erc1155_indexer_tests;
+
+#[cfg(test)]
+pub mod erc1155_token_types_tests;
+
+#[cfg(test)]
+pub mod erc1155_mixed_fungible_tests;
+
+#[cfg(test)]
+pub mod erc1155_royalty_tests;
+
+#[cfg(test)]
+pub mod erc1155_permit_tests;
+
+#[cfg(test)]
+pub mod erc1155_operator_filter_tests;
+
+#[cfg(test)]
+pub mod erc1155_soulbound_tests;
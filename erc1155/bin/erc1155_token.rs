@@ -8,11 +8,29 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    runtime_args, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
-    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
+    bytesrepr::Bytes, runtime_args, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash,
+    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, PublicKey,
+    RuntimeArgs, URef, U256,
 };
 use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
-use erc1155_crate::ERC1155;
+use core::convert::TryFrom;
+use erc1155_crate::{
+    access_control::{BURNER_ROLE, DEFAULT_ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE, URI_SETTER_ROLE},
+    ces::EventsMode,
+    data::ZERO_ADDRESS,
+    AccessControl, ERC1155Burnable, ERC1155Capped, ERC1155Enumerable, ERC1155MixedFungible,
+    ERC1155OperatorFilter, ERC1155Permit, ERC1155Royalty, ERC1155Soulbound, ERC1155Supply,
+    ERC1155TokenTypes, ERC1155URIStorage, OperatorFilterMode, Ownable, Pausable, TokenKind,
+    ERC1155, ERC165,
+};
+
+const OWNER_ROLES: [&str; 5] = [
+    DEFAULT_ADMIN_ROLE,
+    MINTER_ROLE,
+    BURNER_ROLE,
+    PAUSER_ROLE,
+    URI_SETTER_ROLE,
+];
 
 #[derive(Default)]
 struct Token(OnChainContractStorage);
@@ -23,32 +41,341 @@ impl ContractContext<OnChainContractStorage> for Token {
     }
 }
 
-impl ERC1155<OnChainContractStorage> for Token {}
+impl ERC1155<OnChainContractStorage> for Token {
+    fn _before_approval_for_all(&mut self, _owner: Key, operator: Key, approved: bool) {
+        ERC1155OperatorFilter::_filter_approval(self, operator, approved);
+    }
+    fn _before_token_transfer(
+        &mut self,
+        operator: Key,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        _data: Bytes,
+    ) {
+        Pausable::_require_not_paused(self);
+        ERC1155OperatorFilter::_filter_transfer(self, operator, from);
+        ERC1155Soulbound::_enforce_transferable(self, from, to, &ids);
+        // Split ids, fungible or not, carry their base type in the upper 128 bits and are typed
+        // by it rather than by the registry, whose ids stay below that range.
+        let (typed_ids, typed_amounts): (Vec<U256>, Vec<U256>) = ids
+            .iter()
+            .zip(amounts.iter())
+            .filter(|(id, _)| self.get_base_type(**id).is_zero())
+            .unzip();
+        ERC1155TokenTypes::_enforce_token_types(self, from, &typed_ids, &typed_amounts);
+        ERC1155MixedFungible::_update_non_fungible_owners(self, from, to, &ids, &amounts);
+        ERC1155Supply::_update_supply(self, from, to, &ids, &amounts);
+        ERC1155Capped::_enforce_max_supply(self, from, &ids);
+    }
+    fn _after_token_transfer(
+        &mut self,
+        _operator: Key,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        _amounts: Vec<U256>,
+        _data: Bytes,
+    ) {
+        ERC1155Enumerable::_update_enumeration(self, from, to, &ids);
+    }
+}
+impl ERC1155Supply<OnChainContractStorage> for Token {}
+impl ERC1155Enumerable<OnChainContractStorage> for Token {}
+impl ERC1155Capped<OnChainContractStorage> for Token {}
+impl ERC1155Soulbound<OnChainContractStorage> for Token {}
+impl ERC1155TokenTypes<OnChainContractStorage> for Token {}
+impl ERC1155MixedFungible<OnChainContractStorage> for Token {}
+impl ERC1155Royalty<OnChainContractStorage> for Token {}
+impl ERC1155Burnable<OnChainContractStorage> for Token {}
+impl ERC165<OnChainContractStorage> for Token {}
+impl ERC1155Permit<OnChainContractStorage> for Token {}
+impl ERC1155OperatorFilter<OnChainContractStorage> for Token {}
+impl ERC1155URIStorage<OnChainContractStorage> for Token {}
+impl Ownable<OnChainContractStorage> for Token {
+    // The owner holds every role, so they follow ownership and are dropped on renounce.
+    fn _after_ownership_transfer(&mut self, previous_owner: Key, new_owner: Key) {
+        for role in OWNER_ROLES {
+            self._revoke_role(role.into(), previous_owner);
+            if new_owner != ZERO_ADDRESS() {
+                self._grant_role(role.into(), new_owner);
+            }
+        }
+    }
+}
+impl AccessControl<OnChainContractStorage> for Token {}
+impl Pausable<OnChainContractStorage> for Token {}
 
 impl Token {
+    #[allow(clippy::too_many_arguments)]
     fn constructor(
         &mut self,
+        name: String,
+        symbol: String,
         uri: String,
+        contract_uri: String,
+        owner: Key,
         contract_hash: ContractHash,
         package_hash: ContractPackageHash,
+        events_mode: EventsMode,
     ) {
-        ERC1155::init(self, uri, Key::from(contract_hash), package_hash);
+        ERC1155::init(
+            self,
+            name,
+            symbol,
+            uri,
+            Key::from(contract_hash),
+            package_hash,
+            events_mode,
+        );
+        ERC1155::_set_contract_uri(self, contract_uri);
+        ERC1155Supply::init(self);
+        ERC1155Enumerable::init(self);
+        ERC1155Capped::init(self);
+        ERC1155Soulbound::init(self);
+        ERC1155TokenTypes::init(self);
+        ERC1155MixedFungible::init(self);
+        ERC1155Royalty::init(self);
+        ERC1155Burnable::init(self);
+        ERC1155Permit::init(self);
+        ERC1155OperatorFilter::init(self);
+        ERC1155URIStorage::init(self);
+        AccessControl::init(self);
+        Ownable::init(self, owner);
+        Pausable::init(self);
+    }
+    fn mint(&mut self, to: Key, id: U256, amount: U256, data: String) {
+        self.only_role(MINTER_ROLE.into());
+        ERC1155::_mint(self, to, id, amount, Bytes::from(data.as_bytes()));
+    }
+    fn mint_non_fungible(&mut self, type_id: U256, recipients: Vec<Key>) -> Vec<U256> {
+        self.only_role(MINTER_ROLE.into());
+        ERC1155MixedFungible::_mint_non_fungible(self, type_id, recipients)
+    }
+    fn mint_batch(&mut self, to: Key, ids: Vec<U256>, amounts: Vec<U256>, data: String) {
+        self.only_role(MINTER_ROLE.into());
+        ERC1155::_mint_batch(self, to, ids, amounts, Bytes::from(data.as_bytes()));
+    }
+    // Burners may burn from any holder, everyone else goes through the holder/operator check.
+    fn burn(&mut self, from: Key, id: U256, amount: U256) {
+        if self.has_role(BURNER_ROLE.into(), self.get_caller()) {
+            ERC1155::_burn(self, from, id, amount);
+        } else {
+            ERC1155Burnable::burn(self, from, id, amount);
+        }
+    }
+    fn burn_batch(&mut self, from: Key, ids: Vec<U256>, amounts: Vec<U256>) {
+        if self.has_role(BURNER_ROLE.into(), self.get_caller()) {
+            ERC1155::_burn_batch(self, from, ids, amounts);
+        } else {
+            ERC1155Burnable::burn_batch(self, from, ids, amounts);
+        }
+    }
+    fn set_contract_uri(&mut self, contract_uri: String) {
+        self.only_role(DEFAULT_ADMIN_ROLE.into());
+        ERC1155::_set_contract_uri(self, contract_uri);
+    }
+    fn set_uri(&mut self, uri: String) {
+        self.only_role(URI_SETTER_ROLE.into());
+        ERC1155URIStorage::_set_uri(self, uri);
+    }
+    fn set_token_uri(&mut self, id: U256, token_uri: String) {
+        self.only_role(URI_SETTER_ROLE.into());
+        ERC1155URIStorage::_set_token_uri(self, id, token_uri);
+    }
+    fn set_max_supply(&mut self, id: U256, max_supply: U256, immutable: bool) {
+        self.only_role(DEFAULT_ADMIN_ROLE.into());
+        ERC1155Capped::_set_max_supply(self, id, max_supply, immutable);
+    }
+    fn set_default_royalty(&mut self, receiver: Key, fraction: U256) {
+        self.only_role(DEFAULT_ADMIN_ROLE.into());
+        ERC1155Royalty::_set_default_royalty(self, receiver, fraction);
+    }
+    fn delete_default_royalty(&mut self) {
+        self.only_role(DEFAULT_ADMIN_ROLE.into());
+        ERC1155Royalty::_delete_default_royalty(self);
+    }
+    fn set_token_royalty(&mut self, id: U256, receiver: Key, fraction: U256) {
+        self.only_role(DEFAULT_ADMIN_ROLE.into());
+        ERC1155Royalty::_set_token_royalty(self, id, receiver, fraction);
+    }
+    fn reset_token_royalty(&mut self, id: U256) {
+        self.only_role(DEFAULT_ADMIN_ROLE.into());
+        ERC1155Royalty::_reset_token_royalty(self, id);
+    }
+    fn create_token_type(
+        &mut self,
+        kind: TokenKind,
+        metadata_uri: String,
+        max_supply: U256,
+        transferable: bool,
+    ) -> U256 {
+        self.only_role(DEFAULT_ADMIN_ROLE.into());
+        ERC1155TokenTypes::_create_token_type(self, kind, metadata_uri, max_supply, transferable)
+    }
+    fn lock_transfers(&mut self, id: U256) {
+        self.only_role(DEFAULT_ADMIN_ROLE.into());
+        ERC1155Soulbound::_lock_transfers(self, id);
+    }
+    fn pause(&mut self) {
+        self.only_role(PAUSER_ROLE.into());
+        Pausable::_pause(self);
+    }
+    fn unpause(&mut self) {
+        self.only_role(PAUSER_ROLE.into());
+        Pausable::_unpause(self);
     }
 }
 
 #[no_mangle]
 fn constructor() {
+    let name: String = runtime::get_named_arg("name");
+    let symbol: String = runtime::get_named_arg("symbol");
     let uri: String = runtime::get_named_arg("uri");
+    let contract_uri: String = runtime::get_named_arg("contract_uri");
+    let owner: Key = runtime::get_named_arg("owner");
     let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
     let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
-    Token::default().constructor(uri, contract_hash, package_hash);
+    let events_mode: u8 = runtime::get_named_arg("events_mode");
+    let events_mode: EventsMode = EventsMode::try_from(events_mode).unwrap_or_revert();
+    Token::default().constructor(
+        name,
+        symbol,
+        uri,
+        contract_uri,
+        owner,
+        contract_hash,
+        package_hash,
+        events_mode,
+    );
 }
 #[no_mangle]
 fn uri() {
-    let ret: String = Token::default().uri();
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: String = ERC1155URIStorage::uri(&Token::default(), id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn name() {
+    let ret: String = ERC1155::name(&Token::default());
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn symbol() {
+    let ret: String = ERC1155::symbol(&Token::default());
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn contract_uri() {
+    let ret: String = Token::default().contract_uri();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn set_contract_uri() {
+    let contract_uri: String = runtime::get_named_arg("contract_uri");
+    Token::default().set_contract_uri(contract_uri);
+}
+#[no_mangle]
+fn set_uri() {
+    let uri: String = runtime::get_named_arg("uri");
+    Token::default().set_uri(uri);
+}
+#[no_mangle]
+fn set_token_uri() {
+    let id: U256 = runtime::get_named_arg("id");
+    let token_uri: String = runtime::get_named_arg("token_uri");
+    Token::default().set_token_uri(id, token_uri);
+}
+#[no_mangle]
+fn mint() {
+    let to: Key = runtime::get_named_arg("to");
+    let id: U256 = runtime::get_named_arg("id");
+    let amount: U256 = runtime::get_named_arg("amount");
+    let data: String = runtime::get_named_arg("data");
+    Token::default().mint(to, id, amount, data);
+}
+#[no_mangle]
+fn mint_batch() {
+    let to: Key = runtime::get_named_arg("to");
+    let ids: Vec<U256> = runtime::get_named_arg("ids");
+    let amounts: Vec<U256> = runtime::get_named_arg("amounts");
+    let data: String = runtime::get_named_arg("data");
+    Token::default().mint_batch(to, ids, amounts, data);
+}
+#[no_mangle]
+fn burn() {
+    let from: Key = runtime::get_named_arg("from");
+    let id: U256 = runtime::get_named_arg("id");
+    let amount: U256 = runtime::get_named_arg("amount");
+    Token::default().burn(from, id, amount);
+}
+#[no_mangle]
+fn burn_batch() {
+    let from: Key = runtime::get_named_arg("from");
+    let ids: Vec<U256> = runtime::get_named_arg("ids");
+    let amounts: Vec<U256> = runtime::get_named_arg("amounts");
+    Token::default().burn_batch(from, ids, amounts);
+}
+#[no_mangle]
+fn owner() {
+    let ret: Key = Token::default().owner();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn transfer_ownership() {
+    let new_owner: Key = runtime::get_named_arg("new_owner");
+    Token::default().transfer_ownership(new_owner);
+}
+#[no_mangle]
+fn renounce_ownership() {
+    Token::default().renounce_ownership();
+}
+#[no_mangle]
+fn pause() {
+    Token::default().pause();
+}
+#[no_mangle]
+fn unpause() {
+    Token::default().unpause();
+}
+#[no_mangle]
+fn paused() {
+    let ret: bool = Token::default().paused();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn has_role() {
+    let role: String = runtime::get_named_arg("role");
+    let account: Key = runtime::get_named_arg("account");
+    let ret: bool = Token::default().has_role(role, account);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn get_role_admin() {
+    let role: String = runtime::get_named_arg("role");
+    let ret: String = Token::default().get_role_admin(role);
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 #[no_mangle]
+fn grant_role() {
+    let role: String = runtime::get_named_arg("role");
+    let account: Key = runtime::get_named_arg("account");
+    Token::default().grant_role(role, account);
+}
+#[no_mangle]
+fn revoke_role() {
+    let role: String = runtime::get_named_arg("role");
+    let account: Key = runtime::get_named_arg("account");
+    Token::default().revoke_role(role, account);
+}
+#[no_mangle]
+fn renounce_role() {
+    let role: String = runtime::get_named_arg("role");
+    let account: Key = runtime::get_named_arg("account");
+    Token::default().renounce_role(role, account);
+}
+#[no_mangle]
 fn balance_of() {
     let account: Key = runtime::get_named_arg("account");
     let id: U256 = runtime::get_named_arg("id");
@@ -73,6 +400,12 @@ fn set_approval_for_all() {
     Token::default().set_approval_for_all(operator, approved);
 }
 #[no_mangle]
+fn set_approval_for_all_until() {
+    let operator: Key = runtime::get_named_arg("operator");
+    let expires_at: u64 = runtime::get_named_arg("expires_at");
+    Token::default().set_approval_for_all_until(operator, expires_at);
+}
+#[no_mangle]
 fn is_approved_for_all() {
     let account: Key = runtime::get_named_arg("account");
     let operator: Key = runtime::get_named_arg("operator");
@@ -80,6 +413,21 @@ fn is_approved_for_all() {
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 #[no_mangle]
+fn approve() {
+    let operator: Key = runtime::get_named_arg("operator");
+    let id: U256 = runtime::get_named_arg("id");
+    let amount: U256 = runtime::get_named_arg("amount");
+    Token::default().approve(operator, id, amount);
+}
+#[no_mangle]
+fn allowance() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let operator: Key = runtime::get_named_arg("operator");
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: U256 = Token::default().allowance(owner, operator, id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
 fn safe_transfer_from() {
     let from: Key = runtime::get_named_arg("from");
     let to: Key = runtime::get_named_arg("to");
@@ -97,14 +445,254 @@ fn safe_batch_transfer_from() {
     let data: String = runtime::get_named_arg("data");
     Token::default().safe_batch_transfer_from(from, to, ids, amounts, data);
 }
+#[no_mangle]
+fn total_supply() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: U256 = Token::default().total_supply(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn total_supply_all() {
+    let ret: U256 = Token::default().total_supply_all();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn exists() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: bool = Token::default().exists(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn tokens_of_owner() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let page: U256 = runtime::get_named_arg("page");
+    let size: U256 = runtime::get_named_arg("size");
+    let ret: Vec<U256> = Token::default().tokens_of_owner(owner, page, size);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn holders_of() {
+    let id: U256 = runtime::get_named_arg("id");
+    let page: U256 = runtime::get_named_arg("page");
+    let size: U256 = runtime::get_named_arg("size");
+    let ret: Vec<Key> = Token::default().holders_of(id, page, size);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn token_count() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let ret: U256 = Token::default().token_count(owner);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn holder_count() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: U256 = Token::default().holder_count(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn set_max_supply() {
+    let id: U256 = runtime::get_named_arg("id");
+    let max_supply: U256 = runtime::get_named_arg("max_supply");
+    let immutable: bool = runtime::get_named_arg("immutable");
+    Token::default().set_max_supply(id, max_supply, immutable);
+}
+#[no_mangle]
+fn permit_for_all() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let operator: Key = runtime::get_named_arg("operator");
+    let approved: bool = runtime::get_named_arg("approved");
+    let deadline: u64 = runtime::get_named_arg("deadline");
+    let nonce: U256 = runtime::get_named_arg("nonce");
+    let signature: Bytes = runtime::get_named_arg("signature");
+    let public_key: PublicKey = runtime::get_named_arg("public_key");
+    Token::default().permit_for_all(
+        owner, operator, approved, deadline, nonce, signature, public_key,
+    );
+}
+#[no_mangle]
+fn transfer_with_signature() {
+    let from: Key = runtime::get_named_arg("from");
+    let to: Key = runtime::get_named_arg("to");
+    let id: U256 = runtime::get_named_arg("id");
+    let amount: U256 = runtime::get_named_arg("amount");
+    let data: String = runtime::get_named_arg("data");
+    let deadline: u64 = runtime::get_named_arg("deadline");
+    let nonce: U256 = runtime::get_named_arg("nonce");
+    let signature: Bytes = runtime::get_named_arg("signature");
+    let public_key: PublicKey = runtime::get_named_arg("public_key");
+    Token::default().transfer_with_signature(
+        from, to, id, amount, data, deadline, nonce, signature, public_key,
+    );
+}
+#[no_mangle]
+fn batch_transfer_with_signature() {
+    let from: Key = runtime::get_named_arg("from");
+    let to: Key = runtime::get_named_arg("to");
+    let ids: Vec<U256> = runtime::get_named_arg("ids");
+    let amounts: Vec<U256> = runtime::get_named_arg("amounts");
+    let data: String = runtime::get_named_arg("data");
+    let deadline: u64 = runtime::get_named_arg("deadline");
+    let nonce: U256 = runtime::get_named_arg("nonce");
+    let signature: Bytes = runtime::get_named_arg("signature");
+    let public_key: PublicKey = runtime::get_named_arg("public_key");
+    Token::default().batch_transfer_with_signature(
+        from, to, ids, amounts, data, deadline, nonce, signature, public_key,
+    );
+}
+#[no_mangle]
+fn nonces() {
+    let owner: Key = runtime::get_named_arg("owner");
+    let ret: U256 = Token::default().nonces(owner);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn set_operator_filter_mode() {
+    let mode: u8 = runtime::get_named_arg("mode");
+    let mode: OperatorFilterMode = OperatorFilterMode::try_from(mode).unwrap_or_revert();
+    Token::default().set_operator_filter_mode(mode);
+}
+#[no_mangle]
+fn set_operator_listed() {
+    let operator: Key = runtime::get_named_arg("operator");
+    let listed: bool = runtime::get_named_arg("listed");
+    Token::default().set_operator_listed(operator, listed);
+}
+#[no_mangle]
+fn operator_filter_mode() {
+    let ret: u8 = Token::default().operator_filter_mode() as u8;
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn is_operator_allowed() {
+    let operator: Key = runtime::get_named_arg("operator");
+    let ret: bool = Token::default().is_operator_allowed(operator);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn supports_interface() {
+    let interface_id: u32 = runtime::get_named_arg("interface_id");
+    let ret: bool = Token::default().supports_interface(interface_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn royalty_info() {
+    let id: U256 = runtime::get_named_arg("id");
+    let sale_price: U256 = runtime::get_named_arg("sale_price");
+    let ret: (Key, U256) = Token::default().royalty_info(id, sale_price);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn set_default_royalty() {
+    let receiver: Key = runtime::get_named_arg("receiver");
+    let fraction: U256 = runtime::get_named_arg("fraction");
+    Token::default().set_default_royalty(receiver, fraction);
+}
+#[no_mangle]
+fn delete_default_royalty() {
+    Token::default().delete_default_royalty();
+}
+#[no_mangle]
+fn set_token_royalty() {
+    let id: U256 = runtime::get_named_arg("id");
+    let receiver: Key = runtime::get_named_arg("receiver");
+    let fraction: U256 = runtime::get_named_arg("fraction");
+    Token::default().set_token_royalty(id, receiver, fraction);
+}
+#[no_mangle]
+fn reset_token_royalty() {
+    let id: U256 = runtime::get_named_arg("id");
+    Token::default().reset_token_royalty(id);
+}
+#[no_mangle]
+fn mint_non_fungible() {
+    let type_id: U256 = runtime::get_named_arg("type_id");
+    let recipients: Vec<Key> = runtime::get_named_arg("recipients");
+    let ret: Vec<U256> = Token::default().mint_non_fungible(type_id, recipients);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn is_non_fungible() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: bool = Token::default().is_non_fungible(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn get_base_type() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: U256 = Token::default().get_base_type(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn get_index() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: U256 = Token::default().get_index(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn owner_of() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: Key = Token::default().owner_of(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn create_token_type() {
+    let kind: u8 = runtime::get_named_arg("kind");
+    let kind: TokenKind = TokenKind::try_from(kind).unwrap_or_revert();
+    let metadata_uri: String = runtime::get_named_arg("metadata_uri");
+    let max_supply: U256 = runtime::get_named_arg("max_supply");
+    let transferable: bool = runtime::get_named_arg("transferable");
+    let ret: U256 =
+        Token::default().create_token_type(kind, metadata_uri, max_supply, transferable);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn token_kind() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: Option<u8> = Token::default().token_kind(id).map(|kind| kind as u8);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn token_type_count() {
+    let ret: U256 = Token::default().token_type_count();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn lock_transfers() {
+    let id: U256 = runtime::get_named_arg("id");
+    Token::default().lock_transfers(id);
+}
+#[no_mangle]
+fn is_transferable() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: bool = Token::default().is_transferable(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn max_supply() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: U256 = Token::default().max_supply(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn remaining_supply() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: U256 = Token::default().remaining_supply(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 fn get_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(EntryPoint::new(
         "constructor",
         vec![
+            Parameter::new("name", String::cl_type()),
+            Parameter::new("symbol", String::cl_type()),
             Parameter::new("uri", String::cl_type()),
+            Parameter::new("contract_uri", String::cl_type()),
+            Parameter::new("owner", Key::cl_type()),
             Parameter::new("contract_hash", ContractHash::cl_type()),
             Parameter::new("package_hash", ContractPackageHash::cl_type()),
+            Parameter::new("events_mode", u8::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Groups(vec![Group::new("constructor")]),
@@ -112,35 +700,80 @@ fn get_entry_points() -> EntryPoints {
     ));
     entry_points.add_entry_point(EntryPoint::new(
         "uri",
-        vec![],
+        vec![Parameter::new("id", U256::cl_type())],
         String::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
-
     entry_points.add_entry_point(EntryPoint::new(
-        "balance_of",
-        vec![
-            Parameter::new("account", Key::cl_type()),
-            Parameter::new("id", U256::cl_type()),
-        ],
-        bool::cl_type(),
+        "name",
+        vec![],
+        String::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "balance_of_batch",
-        vec![
-            Parameter::new("accounts", CLType::List(Box::new(String::cl_type()))),
-            Parameter::new("ids", CLType::List(Box::new(CLType::U256))),
-        ],
-        CLType::List(Box::new(CLType::U256)),
+        "symbol",
+        vec![],
+        String::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "set_approval_for_all",
-        vec![
+        "contract_uri",
+        vec![],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_contract_uri",
+        vec![Parameter::new("contract_uri", String::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_uri",
+        vec![Parameter::new("uri", String::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_uri",
+        vec![
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("token_uri", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "balance_of",
+        vec![
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("id", U256::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "balance_of_batch",
+        vec![
+            Parameter::new("accounts", CLType::List(Box::new(String::cl_type()))),
+            Parameter::new("ids", CLType::List(Box::new(CLType::U256))),
+        ],
+        CLType::List(Box::new(CLType::U256)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_approval_for_all",
+        vec![
             Parameter::new("operator", Key::cl_type()),
             Parameter::new("approved", bool::cl_type()),
         ],
@@ -148,6 +781,16 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_approval_for_all_until",
+        vec![
+            Parameter::new("operator", Key::cl_type()),
+            Parameter::new("expires_at", u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "is_approved_for_all",
         vec![
@@ -158,6 +801,28 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "approve",
+        vec![
+            Parameter::new("operator", Key::cl_type()),
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "allowance",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("operator", Key::cl_type()),
+            Parameter::new("id", U256::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
         "safe_transfer_from",
         vec![
@@ -184,6 +849,440 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply",
+        vec![Parameter::new("id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply_all",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "exists",
+        vec![Parameter::new("id", U256::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "tokens_of_owner",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("page", U256::cl_type()),
+            Parameter::new("size", U256::cl_type()),
+        ],
+        CLType::List(Box::new(CLType::U256)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "holders_of",
+        vec![
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("page", U256::cl_type()),
+            Parameter::new("size", U256::cl_type()),
+        ],
+        CLType::List(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_count",
+        vec![Parameter::new("owner", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "holder_count",
+        vec![Parameter::new("id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_max_supply",
+        vec![
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("max_supply", U256::cl_type()),
+            Parameter::new("immutable", bool::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "permit_for_all",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("operator", Key::cl_type()),
+            Parameter::new("approved", bool::cl_type()),
+            Parameter::new("deadline", u64::cl_type()),
+            Parameter::new("nonce", U256::cl_type()),
+            Parameter::new("signature", Bytes::cl_type()),
+            Parameter::new("public_key", PublicKey::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_with_signature",
+        vec![
+            Parameter::new("from", Key::cl_type()),
+            Parameter::new("to", Key::cl_type()),
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+            Parameter::new("data", String::cl_type()),
+            Parameter::new("deadline", u64::cl_type()),
+            Parameter::new("nonce", U256::cl_type()),
+            Parameter::new("signature", Bytes::cl_type()),
+            Parameter::new("public_key", PublicKey::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "batch_transfer_with_signature",
+        vec![
+            Parameter::new("from", Key::cl_type()),
+            Parameter::new("to", Key::cl_type()),
+            Parameter::new("ids", CLType::List(Box::new(CLType::U256))),
+            Parameter::new("amounts", CLType::List(Box::new(CLType::U256))),
+            Parameter::new("data", String::cl_type()),
+            Parameter::new("deadline", u64::cl_type()),
+            Parameter::new("nonce", U256::cl_type()),
+            Parameter::new("signature", Bytes::cl_type()),
+            Parameter::new("public_key", PublicKey::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "nonces",
+        vec![Parameter::new("owner", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_operator_filter_mode",
+        vec![Parameter::new("mode", u8::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_operator_listed",
+        vec![
+            Parameter::new("operator", Key::cl_type()),
+            Parameter::new("listed", bool::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "operator_filter_mode",
+        vec![],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_operator_allowed",
+        vec![Parameter::new("operator", Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "supports_interface",
+        vec![Parameter::new("interface_id", u32::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "royalty_info",
+        vec![
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("sale_price", U256::cl_type()),
+        ],
+        <(Key, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_default_royalty",
+        vec![
+            Parameter::new("receiver", Key::cl_type()),
+            Parameter::new("fraction", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "delete_default_royalty",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_royalty",
+        vec![
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("receiver", Key::cl_type()),
+            Parameter::new("fraction", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "reset_token_royalty",
+        vec![Parameter::new("id", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_non_fungible",
+        vec![
+            Parameter::new("type_id", U256::cl_type()),
+            Parameter::new("recipients", CLType::List(Box::new(CLType::Key))),
+        ],
+        CLType::List(Box::new(CLType::U256)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_non_fungible",
+        vec![Parameter::new("id", U256::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_base_type",
+        vec![Parameter::new("id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_index",
+        vec![Parameter::new("id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "owner_of",
+        vec![Parameter::new("id", U256::cl_type())],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "create_token_type",
+        vec![
+            Parameter::new("kind", u8::cl_type()),
+            Parameter::new("metadata_uri", String::cl_type()),
+            Parameter::new("max_supply", U256::cl_type()),
+            Parameter::new("transferable", bool::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_kind",
+        vec![Parameter::new("id", U256::cl_type())],
+        CLType::Option(Box::new(CLType::U8)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_type_count",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "lock_transfers",
+        vec![Parameter::new("id", U256::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_transferable",
+        vec![Parameter::new("id", U256::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "max_supply",
+        vec![Parameter::new("id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "remaining_supply",
+        vec![Parameter::new("id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint",
+        vec![
+            Parameter::new("to", Key::cl_type()),
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+            Parameter::new("data", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint_batch",
+        vec![
+            Parameter::new("to", Key::cl_type()),
+            Parameter::new("ids", CLType::List(Box::new(CLType::U256))),
+            Parameter::new("amounts", CLType::List(Box::new(CLType::U256))),
+            Parameter::new("data", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "burn",
+        vec![
+            Parameter::new("from", Key::cl_type()),
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("amount", U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "burn_batch",
+        vec![
+            Parameter::new("from", Key::cl_type()),
+            Parameter::new("ids", CLType::List(Box::new(CLType::U256))),
+            Parameter::new("amounts", CLType::List(Box::new(CLType::U256))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "owner",
+        vec![],
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_ownership",
+        vec![Parameter::new("new_owner", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "renounce_ownership",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "has_role",
+        vec![
+            Parameter::new("role", String::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_role_admin",
+        vec![Parameter::new("role", String::cl_type())],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_role",
+        vec![
+            Parameter::new("role", String::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_role",
+        vec![
+            Parameter::new("role", String::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "renounce_role",
+        vec![
+            Parameter::new("role", String::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "pause",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "unpause",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "paused",
+        vec![],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points
 }
 
@@ -196,13 +1295,22 @@ fn call() {
         let (contract_hash, _) =
             storage::add_contract_version(package_hash, get_entry_points(), Default::default());
 
+        let name: String = runtime::get_named_arg("name");
+        let symbol: String = runtime::get_named_arg("symbol");
         let uri: String = runtime::get_named_arg("uri");
+        let contract_uri: String = runtime::get_named_arg("contract_uri");
+        let events_mode: u8 = runtime::get_named_arg("events_mode");
 
         // Prepare constructor args
         let constructor_args = runtime_args! {
+            "name" => name,
+            "symbol" => symbol,
             "uri" => uri,
+            "contract_uri" => contract_uri,
+            "owner" => Key::from(runtime::get_caller()),
             "contract_hash" => contract_hash,
-            "package_hash"=> package_hash
+            "package_hash"=> package_hash,
+            "events_mode" => events_mode
 
         };
 
@@ -3,3 +3,6 @@ pub mod mock_contract_tests;
 
 #[cfg(test)]
 pub mod mock_contract_instance;
+
+#[cfg(test)]
+pub mod mock_receiver_instance;
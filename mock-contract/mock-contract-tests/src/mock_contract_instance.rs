@@ -133,6 +133,27 @@ impl MOCKCONTRACTInstance {
             0,
         );
     }
+    pub fn set_uri(&self, sender: AccountHash, uri: String) {
+        self.0.call_contract(
+            sender,
+            "set_uri",
+            runtime_args! {
+                "uri" => uri
+            },
+            0,
+        );
+    }
+    pub fn set_token_uri(&self, sender: AccountHash, id: U256, token_uri: String) {
+        self.0.call_contract(
+            sender,
+            "set_token_uri",
+            runtime_args! {
+                "id" => id,
+                "token_uri" => token_uri
+            },
+            0,
+        );
+    }
     
     // Result methods
     pub fn result<T: CLTyped + FromBytes>(&self) -> T {
@@ -1,4 +1,5 @@
 use crate::mock_contract_instance::MOCKCONTRACTInstance;
+use crate::mock_receiver_instance::MOCKRECEIVERInstance;
 use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
 use casperlabs_test_env::{TestContract, TestEnv};
 use common::keys::*;
@@ -8,6 +9,63 @@ fn deploy() -> (TestEnv, AccountHash, TestContract) {
     let instance = MOCKCONTRACTInstance::new_deploy(&env, "MOCKCONTRACT", owner, "sa".to_string());
     (env, owner, instance)
 }
+fn deploy_receiver(env: &TestEnv, owner: AccountHash, accept: bool) -> MOCKRECEIVERInstance {
+    let receiver = MOCKRECEIVERInstance::new_deploy(env, "MOCKRECEIVER", owner, accept);
+    MOCKRECEIVERInstance::contract_instance(receiver)
+}
+fn balance_of(
+    env: &TestEnv,
+    owner: AccountHash,
+    package_hash: [u8; 32],
+    account: Key,
+    id: U256,
+) -> U256 {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(BALANCE_OF),
+            "package_hash" => Key::Hash(package_hash),
+            "owner"=>Key::from(owner),
+            "account"=>account,
+            "id"=>id
+        },
+        0,
+    );
+    env.query_account_named_key(owner, &[BALANCE_OF.into()])
+}
+fn uri(env: &TestEnv, owner: AccountHash, package_hash: [u8; 32], id: U256) -> String {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(URI),
+            "package_hash" => Key::Hash(package_hash),
+            "id"=>id
+        },
+        0,
+    );
+    env.query_account_named_key(owner, &[URI.into()])
+}
+fn total_supply(env: &TestEnv, owner: AccountHash, package_hash: [u8; 32], id: U256) -> U256 {
+    TestContract::new(
+        env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(TOTAL_SUPPLY),
+            "package_hash" => Key::Hash(package_hash),
+            "id"=>id
+        },
+        0,
+    );
+    env.query_account_named_key(owner, &[TOTAL_SUPPLY.into()])
+}
 
 #[test]
 fn test_deploy() {
@@ -387,3 +445,253 @@ fn test_burn_batch() {
     let ret: U256 = env.query_account_named_key(owner, &[BALANCE_OF.into()]);
     assert_eq!(ret, 40.into());
 }
+#[test]
+fn test_mint_to_receiver() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    let receiver = deploy_receiver(&env, owner, true);
+    let to: Key = Key::Hash(receiver.package_hash());
+    let id: U256 = 1.into();
+    instance.mint(owner, to, id, 100.into(), "0x00".to_string());
+    let ret: U256 = balance_of(&env, owner, instance.package_hash(), to, id);
+    assert_eq!(ret, 100.into());
+}
+#[test]
+#[should_panic]
+fn test_mint_to_rejecting_receiver() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    let receiver = deploy_receiver(&env, owner, false);
+    let to: Key = Key::Hash(receiver.package_hash());
+    instance.mint(owner, to, 1.into(), 100.into(), "0x00".to_string());
+}
+#[test]
+fn test_safe_batch_transfer_from_to_receiver() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    let receiver = deploy_receiver(&env, owner, true);
+    let to: Key = Key::Hash(receiver.package_hash());
+    let id1: U256 = 1.into();
+    let id2: U256 = 2.into();
+    instance.mint(
+        owner,
+        Key::Account(owner),
+        id1,
+        100.into(),
+        "0x00".to_string(),
+    );
+    instance.mint(
+        owner,
+        Key::Account(owner),
+        id2,
+        50.into(),
+        "0x00".to_string(),
+    );
+    instance.safe_batch_transfer_from(
+        owner,
+        Key::Account(owner),
+        to,
+        vec![id1, id2],
+        vec![20.into(), 10.into()],
+        "0x00".to_string(),
+    );
+    let ret: U256 = balance_of(&env, owner, instance.package_hash(), to, id1);
+    assert_eq!(ret, 20.into());
+    let ret: U256 = balance_of(&env, owner, instance.package_hash(), to, id2);
+    assert_eq!(ret, 10.into());
+}
+#[test]
+#[should_panic]
+fn test_safe_transfer_from_to_receiver_after_reject() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    let receiver = deploy_receiver(&env, owner, true);
+    let to: Key = Key::Hash(receiver.package_hash());
+    let id: U256 = 1.into();
+    instance.mint(
+        owner,
+        Key::Account(owner),
+        id,
+        100.into(),
+        "0x00".to_string(),
+    );
+    receiver.set_accept(owner, false);
+    instance.safe_transfer_from(
+        owner,
+        Key::Account(owner),
+        to,
+        id,
+        40.into(),
+        "0x00".to_string(),
+    );
+}
+#[test]
+fn test_total_supply() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    let id: U256 = 1.into();
+    let user_hash = env.next_user();
+    let user: Key = Key::Account(user_hash);
+    instance.mint(
+        owner,
+        Key::Account(owner),
+        id,
+        100.into(),
+        "0x00".to_string(),
+    );
+    instance.mint(owner, user, id, 50.into(), "0x00".to_string());
+    let ret: U256 = total_supply(&env, owner, instance.package_hash(), id);
+    assert_eq!(ret, 150.into());
+    instance.safe_transfer_from(
+        owner,
+        Key::Account(owner),
+        user,
+        id,
+        30.into(),
+        "0x00".to_string(),
+    );
+    let ret: U256 = total_supply(&env, owner, instance.package_hash(), id);
+    assert_eq!(ret, 150.into());
+    instance.burn(user_hash, user, id, 80.into());
+    let ret: U256 = total_supply(&env, owner, instance.package_hash(), id);
+    assert_eq!(ret, 70.into());
+}
+#[test]
+fn test_total_supply_batch() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    let to: Key = Key::Account(owner);
+    let id1: U256 = 1.into();
+    let id2: U256 = 2.into();
+    instance.mint(owner, to, id1, 100.into(), "0x00".to_string());
+    instance.mint(owner, to, id2, 50.into(), "0x00".to_string());
+    instance.burn_batch(owner, to, vec![id1, id2], vec![20.into(), 50.into()]);
+    let ret: U256 = total_supply(&env, owner, instance.package_hash(), id1);
+    assert_eq!(ret, 80.into());
+    let ret: U256 = total_supply(&env, owner, instance.package_hash(), id2);
+    assert_eq!(ret, 0.into());
+    TestContract::new(
+        &env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(TOTAL_SUPPLY_ALL),
+            "package_hash" => Key::Hash(instance.package_hash()),
+        },
+        0,
+    );
+    let ret: U256 = env.query_account_named_key(owner, &[TOTAL_SUPPLY_ALL.into()]);
+    assert_eq!(ret, 80.into());
+}
+#[test]
+fn test_exists() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    let to: Key = Key::Account(owner);
+    let id: U256 = 1.into();
+    instance.mint(owner, to, id, 100.into(), "0x00".to_string());
+    TestContract::new(
+        &env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(EXISTS),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "id"=>id
+        },
+        0,
+    );
+    let ret: bool = env.query_account_named_key(owner, &[EXISTS.into()]);
+    assert!(ret);
+    instance.burn(owner, to, id, 100.into());
+    TestContract::new(
+        &env,
+        "erc1155-session-code.wasm",
+        "SessionCode",
+        owner,
+        runtime_args! {
+            "entrypoint" => String::from(EXISTS),
+            "package_hash" => Key::Hash(instance.package_hash()),
+            "id"=>id
+        },
+        0,
+    );
+    let ret: bool = env.query_account_named_key(owner, &[EXISTS.into()]);
+    assert!(!ret);
+}
+#[test]
+fn test_uri_id_substitution() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    instance.set_uri(owner, "https://token-cdn-domain/{id}.json".to_string());
+    let ret: String = uri(&env, owner, instance.package_hash(), 314592.into());
+    assert_eq!(
+        ret,
+        "https://token-cdn-domain/000000000000000000000000000000000000000000000000000000000004cce0.json"
+    );
+}
+#[test]
+fn test_set_token_uri() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    instance.set_uri(owner, "https://token-cdn-domain/{id}.json".to_string());
+    instance.set_token_uri(owner, 2.into(), "ipfs://token-two".to_string());
+    let ret: String = uri(&env, owner, instance.package_hash(), 2.into());
+    assert_eq!(ret, "ipfs://token-two");
+    let ret: String = uri(&env, owner, instance.package_hash(), 1.into());
+    assert_eq!(
+        ret,
+        "https://token-cdn-domain/0000000000000000000000000000000000000000000000000000000000000001.json"
+    );
+}
+#[test]
+#[should_panic]
+fn test_set_uri_by_non_admin() {
+    let (env, _, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    instance.set_uri(
+        env.next_user(),
+        "https://token-cdn-domain/{id}.json".to_string(),
+    );
+}
+#[test]
+#[should_panic]
+fn test_set_token_uri_by_non_admin() {
+    let (env, _, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    instance.set_token_uri(env.next_user(), 2.into(), "ipfs://token-two".to_string());
+}
+#[test]
+fn test_burn_by_approved_operator() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    let operator = env.next_user();
+    let from: Key = Key::Account(owner);
+    let id: U256 = 1.into();
+    instance.mint(owner, from, id, 100.into(), "0x00".to_string());
+    instance.set_approval_for_all(owner, Key::Account(operator), true);
+    instance.burn(operator, from, id, 40.into());
+    instance.burn_batch(operator, from, vec![id], vec![10.into()]);
+    let ret: U256 = balance_of(&env, owner, instance.package_hash(), from, id);
+    assert_eq!(ret, 50.into());
+}
+#[test]
+#[should_panic]
+fn test_burn_by_non_approved() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    let from: Key = Key::Account(owner);
+    instance.mint(owner, from, 1.into(), 100.into(), "0x00".to_string());
+    instance.burn(env.next_user(), from, 1.into(), 40.into());
+}
+#[test]
+#[should_panic]
+fn test_burn_batch_by_non_approved() {
+    let (env, owner, instance) = deploy();
+    let instance = MOCKCONTRACTInstance::contract_instance(instance);
+    let from: Key = Key::Account(owner);
+    instance.mint(owner, from, 1.into(), 100.into(), "0x00".to_string());
+    instance.burn_batch(env.next_user(), from, vec![1.into()], vec![40.into()]);
+}
@@ -0,0 +1,41 @@
+use casper_types::{account::AccountHash, runtime_args, RuntimeArgs};
+use casperlabs_test_env::{TestContract, TestEnv};
+
+pub struct MOCKRECEIVERInstance(TestContract);
+
+impl MOCKRECEIVERInstance {
+    pub fn contract_instance(contract: TestContract) -> MOCKRECEIVERInstance {
+        MOCKRECEIVERInstance(contract)
+    }
+    pub fn new_deploy(
+        env: &TestEnv,
+        contract_name: &str,
+        sender: AccountHash,
+        accept: bool,
+    ) -> TestContract {
+        TestContract::new(
+            env,
+            "mock-receiver.wasm",
+            contract_name,
+            sender,
+            runtime_args! {
+                "accept" => accept
+            },
+            0,
+        )
+    }
+    pub fn set_accept(&self, sender: AccountHash, accept: bool) {
+        self.0.call_contract(
+            sender,
+            "set_accept",
+            runtime_args! {
+                "accept" => accept
+            },
+            0,
+        );
+    }
+
+    pub fn package_hash(&self) -> [u8; 32] {
+        self.0.package_hash()
+    }
+}
@@ -9,11 +9,15 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    runtime_args, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint,
-    EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
+    bytesrepr::Bytes, runtime_args, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash,
+    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs,
+    URef, U256,
 };
 use casperlabs_contract_utils::{ContractContext, OnChainContractStorage};
-use erc1155_crate::ERC1155;
+use erc1155_crate::{
+    access_control::{DEFAULT_ADMIN_ROLE, URI_SETTER_ROLE},
+    AccessControl, ERC1155Burnable, ERC1155Supply, ERC1155URIStorage, ERC1155,
+};
 use mock_contract_crate::MOCKCONTRACT;
 
 #[derive(Default)]
@@ -24,25 +28,55 @@ impl ContractContext<OnChainContractStorage> for MockContract {
         &self.0
     }
 }
-impl ERC1155<OnChainContractStorage> for MockContract {}
+impl ERC1155<OnChainContractStorage> for MockContract {
+    fn _before_token_transfer(
+        &mut self,
+        _operator: Key,
+        from: Key,
+        to: Key,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        _data: Bytes,
+    ) {
+        ERC1155Supply::_update_supply(self, from, to, &ids, &amounts);
+    }
+}
+impl ERC1155Supply<OnChainContractStorage> for MockContract {}
+impl ERC1155Burnable<OnChainContractStorage> for MockContract {}
+impl ERC1155URIStorage<OnChainContractStorage> for MockContract {}
+impl AccessControl<OnChainContractStorage> for MockContract {}
 impl MOCKCONTRACT<OnChainContractStorage> for MockContract {}
 impl MockContract {
     fn constructor(
         &mut self,
         uri: String,
+        owner: Key,
         contract_hash: ContractHash,
         package_hash: ContractPackageHash,
     ) {
         MOCKCONTRACT::init(self, uri, Key::from(contract_hash), package_hash);
+        AccessControl::init(self);
+        for role in [DEFAULT_ADMIN_ROLE, URI_SETTER_ROLE] {
+            self._grant_role(role.into(), owner);
+        }
+    }
+    fn set_uri(&mut self, uri: String) {
+        self.only_role(URI_SETTER_ROLE.into());
+        ERC1155URIStorage::_set_uri(self, uri);
+    }
+    fn set_token_uri(&mut self, id: U256, token_uri: String) {
+        self.only_role(URI_SETTER_ROLE.into());
+        ERC1155URIStorage::_set_token_uri(self, id, token_uri);
     }
 }
 
 #[no_mangle]
 fn constructor() {
     let uri: String = runtime::get_named_arg("uri");
+    let owner: Key = runtime::get_named_arg("owner");
     let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
     let package_hash: ContractPackageHash = runtime::get_named_arg("package_hash");
-    MockContract::default().constructor(uri, contract_hash, package_hash);
+    MockContract::default().constructor(uri, owner, contract_hash, package_hash);
 }
 #[no_mangle]
 fn mint() {
@@ -114,7 +148,41 @@ fn burn_batch() {
     let from: Key = runtime::get_named_arg("from");
     let ids: Vec<U256> = runtime::get_named_arg("ids");
     let amounts: Vec<U256> = runtime::get_named_arg("amounts");
-    MockContract::default()._burn_batch(from, ids, amounts);
+    MockContract::default().burn_batch(from, ids, amounts);
+}
+#[no_mangle]
+fn uri() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: String = ERC1155URIStorage::uri(&MockContract::default(), id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn set_uri() {
+    let uri: String = runtime::get_named_arg("uri");
+    MockContract::default().set_uri(uri);
+}
+#[no_mangle]
+fn set_token_uri() {
+    let id: U256 = runtime::get_named_arg("id");
+    let token_uri: String = runtime::get_named_arg("token_uri");
+    MockContract::default().set_token_uri(id, token_uri);
+}
+#[no_mangle]
+fn total_supply() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: U256 = MockContract::default().total_supply(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn total_supply_all() {
+    let ret: U256 = MockContract::default().total_supply_all();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn exists() {
+    let id: U256 = runtime::get_named_arg("id");
+    let ret: bool = MockContract::default().exists(id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
 fn get_entry_points() -> EntryPoints {
@@ -123,6 +191,7 @@ fn get_entry_points() -> EntryPoints {
         "constructor",
         vec![
             Parameter::new("uri", String::cl_type()),
+            Parameter::new("owner", Key::cl_type()),
             Parameter::new("contract_hash", ContractHash::cl_type()),
             Parameter::new("package_hash", ContractPackageHash::cl_type()),
         ],
@@ -230,7 +299,51 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
-    
+    entry_points.add_entry_point(EntryPoint::new(
+        "uri",
+        vec![Parameter::new("id", U256::cl_type())],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_uri",
+        vec![Parameter::new("uri", String::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_uri",
+        vec![
+            Parameter::new("id", U256::cl_type()),
+            Parameter::new("token_uri", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply",
+        vec![Parameter::new("id", U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply_all",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "exists",
+        vec![Parameter::new("id", U256::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points
 }
 
@@ -249,6 +362,7 @@ fn call() {
         // Prepare constructor args
         let constructor_args = runtime_args! {
             "uri" => uri,
+            "owner" => Key::from(runtime::get_caller()),
             "contract_hash" => contract_hash,
             "package_hash"=> package_hash
         };
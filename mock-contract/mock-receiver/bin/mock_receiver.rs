@@ -0,0 +1,182 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeSet, format, vec, vec::Vec};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::Bytes, runtime_args, CLTyped, CLValue, ContractHash, ContractPackageHash,
+    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs,
+    URef, U256,
+};
+use casperlabs_contract_utils::{get_key, set_key, ContractContext, OnChainContractStorage};
+use erc1155_crate::{add_receiver_entry_points, ERC1155Receiver, ReceiverPolicy, ERC165};
+
+const ACCEPT: &str = "accept";
+
+#[derive(Default)]
+struct MockReceiver(OnChainContractStorage);
+
+impl ContractContext<OnChainContractStorage> for MockReceiver {
+    fn storage(&self) -> &OnChainContractStorage {
+        &self.0
+    }
+}
+impl ERC1155Receiver<OnChainContractStorage> for MockReceiver {
+    fn receiver_policy(&self) -> ReceiverPolicy {
+        let accept: bool = get_key(ACCEPT).unwrap_or_revert();
+        if accept {
+            ReceiverPolicy::AcceptAll
+        } else {
+            ReceiverPolicy::RejectAll
+        }
+    }
+}
+impl ERC165<OnChainContractStorage> for MockReceiver {}
+
+#[no_mangle]
+fn constructor() {
+    let accept: bool = runtime::get_named_arg("accept");
+    set_key(ACCEPT, accept);
+    ERC1155Receiver::init(&MockReceiver::default());
+}
+#[no_mangle]
+fn set_accept() {
+    let accept: bool = runtime::get_named_arg("accept");
+    set_key(ACCEPT, accept);
+}
+#[no_mangle]
+fn supports_interface() {
+    let interface_id: u32 = runtime::get_named_arg("interface_id");
+    let ret: bool = MockReceiver::default().supports_interface(interface_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn on_erc1155_received() {
+    let operator: Key = runtime::get_named_arg("operator");
+    let from: Key = runtime::get_named_arg("from");
+    let id: U256 = runtime::get_named_arg("id");
+    let amount: U256 = runtime::get_named_arg("amount");
+    let data: Bytes = runtime::get_named_arg("data");
+    let ret: u32 = MockReceiver::default().on_erc1155_received(operator, from, id, amount, data);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+#[no_mangle]
+fn on_erc1155_batch_received() {
+    let operator: Key = runtime::get_named_arg("operator");
+    let from: Key = runtime::get_named_arg("from");
+    let ids: Vec<U256> = runtime::get_named_arg("ids");
+    let amounts: Vec<U256> = runtime::get_named_arg("amounts");
+    let data: Bytes = runtime::get_named_arg("data");
+    let ret: u32 =
+        MockReceiver::default().on_erc1155_batch_received(operator, from, ids, amounts, data);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![Parameter::new("accept", bool::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_accept",
+        vec![Parameter::new("accept", bool::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "supports_interface",
+        vec![Parameter::new("interface_id", u32::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    add_receiver_entry_points(&mut entry_points);
+    entry_points
+}
+
+#[no_mangle]
+fn call() {
+    // Contract name must be same for all new versions of the contracts
+    let contract_name: alloc::string::String = runtime::get_named_arg("contract_name");
+
+    // If this is the first deployment
+    if !runtime::has_key(&format!("{}_package_hash", contract_name)) {
+        // Build new package with initial a first version of the contract.
+        let (package_hash, access_token) = storage::create_contract_package_at_hash();
+        let (contract_hash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+        let accept: bool = runtime::get_named_arg("accept");
+        // Prepare constructor args
+        let constructor_args = runtime_args! {
+            "accept" => accept
+        };
+
+        // Add the constructor group to the package hash with a single URef.
+        let constructor_access: URef =
+            storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+                .unwrap_or_revert()
+                .pop()
+                .unwrap_or_revert();
+
+        // Call the constructor entry point
+        let _: () =
+            runtime::call_versioned_contract(package_hash, None, "constructor", constructor_args);
+
+        // Remove all URefs from the constructor group, so no one can call it for the second time.
+        let mut urefs = BTreeSet::new();
+        urefs.insert(constructor_access);
+        storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+            .unwrap_or_revert();
+        runtime::put_key(
+            &format!("{}_package_hash", contract_name),
+            package_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_hash_wrapped", contract_name),
+            storage::new_uref(package_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+        runtime::put_key(
+            &format!("{}_package_access_token", contract_name),
+            access_token.into(),
+        );
+    } else {
+        // this is a contract upgrade
+        let package_hash: ContractPackageHash =
+            runtime::get_key(&format!("{}_package_hash", contract_name))
+                .unwrap_or_revert()
+                .into_hash()
+                .unwrap()
+                .into();
+
+        let (contract_hash, _): (ContractHash, _) =
+            storage::add_contract_version(package_hash, get_entry_points(), Default::default());
+
+        // update contract hash
+        runtime::put_key(
+            &format!("{}_contract_hash", contract_name),
+            contract_hash.into(),
+        );
+        runtime::put_key(
+            &format!("{}_contract_hash_wrapped", contract_name),
+            storage::new_uref(contract_hash).into(),
+        );
+    }
+}